@@ -0,0 +1,118 @@
+/*
+Measures short-circuiting iterator methods (`any`, `position`, `rev().any()`,
+etc.), which are implemented using `try_fold`/`try_rfold` by default.
+
+Without the `iter::TryFold` argument, the enum forwards only `find`, `find_map`,
+and `rfind`, so the other methods fall back to calling `next`/`next_back`
+repeatedly. With the argument, `try_fold` and `try_rfold` are forwarded and the
+inner iterator's specialized implementation is used. This bench passes the
+argument when the `unstable` feature is enabled. Compare the two runs:
+
+$ cargo bench --bench try_fold | tee bench.txt
+$ cargo +nightly bench --bench try_fold --features unstable | tee bench-try_fold.txt
+*/
+
+#![cfg_attr(feature = "unstable", feature(try_trait_v2))]
+#![warn(rust_2018_idioms, single_use_lifetimes)]
+
+use auto_enums::auto_enum;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::Rng;
+
+// `Chain` and `Flatten` have their own `try_fold` implementations, which are
+// considerably faster than repeatedly calling `next`.
+
+fn iter_no_branch(_x: u32) -> impl DoubleEndedIterator<Item = i64> {
+    (0..500).chain(500..1000).map(|x| black_box(x + 2 - 1))
+}
+
+#[cfg_attr(feature = "unstable", auto_enum(DoubleEndedIterator, iter::TryFold))]
+#[cfg_attr(not(feature = "unstable"), auto_enum(DoubleEndedIterator))]
+fn iter_enum2(x: u32) -> impl DoubleEndedIterator<Item = i64> {
+    match x % 2 {
+        0 => (0..500).chain(500..1000).map(|x| black_box((x * 2) - 1)),
+        _ => (0..10).flat_map(|x| x * 100..x * 100 + 100).map(|x| black_box((x + 1) / 2)),
+    }
+}
+
+#[cfg_attr(feature = "unstable", auto_enum(DoubleEndedIterator, iter::TryFold))]
+#[cfg_attr(not(feature = "unstable"), auto_enum(DoubleEndedIterator))]
+fn iter_enum4(x: u32) -> impl DoubleEndedIterator<Item = i64> {
+    match x % 4 {
+        0 => (0..500).chain(500..1000).map(|x| black_box((x * 2) * 2)),
+        1 => (0..10).flat_map(|x| x * 100..x * 100 + 100).map(|x| black_box((x - 1) / 2)),
+        2 => (0..250).chain(250..1000).map(|x| black_box((x * 2) - 1)),
+        _ => (0..20).flat_map(|x| x * 50..x * 50 + 50).map(|x| black_box((x + 1) / 2)),
+    }
+}
+
+macro_rules! bench_any {
+    ($($fn:ident, $iter:ident, $max:expr, $num:expr,)*) => {
+        $(
+            fn $fn(c: &mut Criterion) {
+                let mut rng = rand::thread_rng();
+                c.bench_function(stringify!($fn), |b| {
+                    b.iter(|| {
+                        let mut iter = $iter(rng.gen_range(0..$max));
+                        assert!(!iter.any(|x| x > $num))
+                    })
+                });
+            }
+        )*
+        criterion_group!(bench_any, $($fn),*);
+    };
+}
+
+macro_rules! bench_position {
+    ($($fn:ident, $iter:ident, $max:expr, $num:expr,)*) => {
+        $(
+            fn $fn(c: &mut Criterion) {
+                let mut rng = rand::thread_rng();
+                c.bench_function(stringify!($fn), |b| {
+                    b.iter(|| {
+                        let mut iter = $iter(rng.gen_range(0..$max));
+                        assert!(iter.position(|x| x > $num).is_none())
+                    })
+                });
+            }
+        )*
+        criterion_group!(bench_position, $($fn),*);
+    };
+}
+
+macro_rules! bench_rev_any {
+    ($($fn:ident, $iter:ident, $max:expr, $num:expr,)*) => {
+        $(
+            fn $fn(c: &mut Criterion) {
+                let mut rng = rand::thread_rng();
+                c.bench_function(stringify!($fn), |b| {
+                    b.iter(|| {
+                        let iter = $iter(rng.gen_range(0..$max));
+                        assert!(!iter.rev().any(|x| x > $num))
+                    })
+                });
+            }
+        )*
+        criterion_group!(bench_rev_any, $($fn),*);
+    };
+}
+
+bench_any! {
+    bench_any_enum02, iter_enum2, 2, 10000,
+    bench_any_enum04, iter_enum4, 4, 10000,
+    bench_any_no_branch, iter_no_branch, 10, 10000,
+}
+
+bench_position! {
+    bench_position_enum02, iter_enum2, 2, 10000,
+    bench_position_enum04, iter_enum4, 4, 10000,
+    bench_position_no_branch, iter_no_branch, 10, 10000,
+}
+
+bench_rev_any! {
+    bench_rev_any_enum02, iter_enum2, 2, 10000,
+    bench_rev_any_enum04, iter_enum4, 4, 10000,
+    bench_rev_any_no_branch, iter_no_branch, 10, 10000,
+}
+
+criterion_main!(bench_any, bench_position, bench_rev_any);
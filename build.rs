@@ -18,7 +18,47 @@ fn main() {
         }
     };
 
+    // Custom cfgs emitted below must also be added to this list.
+    if cfg.probe_rustc_version(1, 80) {
+        println!(
+            "cargo:rustc-check-cfg=cfg(\
+             stable_1_36,stable_1_37,stable_1_51,stable_1_55,stable_1_56,stable_1_63,stable_1_64,\
+             stable_1_80,stable_1_81,stable_1_83,coroutine_trait_renamed)"
+        );
+    }
+
     if cfg.probe_rustc_version(1, 36) {
         println!("cargo:rustc-cfg=stable_1_36");
     }
+    if cfg.probe_rustc_version(1, 37) {
+        println!("cargo:rustc-cfg=stable_1_37");
+    }
+    if cfg.probe_rustc_version(1, 51) {
+        println!("cargo:rustc-cfg=stable_1_51");
+    }
+    if cfg.probe_rustc_version(1, 55) {
+        println!("cargo:rustc-cfg=stable_1_55");
+    }
+    if cfg.probe_rustc_version(1, 56) {
+        println!("cargo:rustc-cfg=stable_1_56");
+    }
+    if cfg.probe_rustc_version(1, 63) {
+        println!("cargo:rustc-cfg=stable_1_63");
+    }
+    if cfg.probe_rustc_version(1, 64) {
+        println!("cargo:rustc-cfg=stable_1_64");
+    }
+    if cfg.probe_rustc_version(1, 80) {
+        println!("cargo:rustc-cfg=stable_1_80");
+    }
+    if cfg.probe_rustc_version(1, 81) {
+        println!("cargo:rustc-cfg=stable_1_81");
+    }
+    if cfg.probe_rustc_version(1, 83) {
+        println!("cargo:rustc-cfg=stable_1_83");
+    }
+    // https://github.com/rust-lang/rust/pull/116958
+    if cfg.probe_rustc_version(1, 75) {
+        println!("cargo:rustc-cfg=coroutine_trait_renamed");
+    }
 }
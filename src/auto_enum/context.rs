@@ -1,16 +1,21 @@
 use std::{collections::hash_map::DefaultHasher, hash::Hasher, iter, mem};
 
 use proc_macro2::TokenStream;
-use quote::format_ident;
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 #[cfg(feature = "type_analysis")]
 use syn::Type;
 use syn::{
+    parenthesized,
     parse::{Parse, ParseStream},
-    parse_quote, Attribute, Error, Expr, Ident, ItemEnum, Macro, Path, Result, Token,
+    parse_quote, spanned::Spanned, token, Attribute, Error, Expr, Ident, Item, Macro, Path, Result,
+    Token,
 };
 
 use super::visitor::{Dummy, Visitor};
-use crate::utils::{expr_call, path, replace_expr, unit, VisitedNode};
+use crate::{
+    enum_derive::parse_crates,
+    utils::{expr_call, path, replace_expr, unit, VisitedNode},
+};
 
 // =================================================================================================
 // Context
@@ -69,6 +74,8 @@ pub(super) struct Context {
     diagnostic: Diagnostic,
 
     pub(super) args: Vec<Path>,
+    /// The `crate(...)` argument passed through to `#[enum_derive]`.
+    crates: Vec<(Ident, Path)>,
     #[cfg(feature = "type_analysis")]
     traits: Vec<Path>,
 }
@@ -81,7 +88,7 @@ impl Context {
         mut markers: Vec<String>,
         diagnostic: Diagnostic,
     ) -> Result<Self> {
-        let Args { args, marker } = syn::parse2(args)?;
+        let Args { args, marker, crates, inspect, instrument } = syn::parse2(args)?;
 
         let marker = if let Some(marker) = marker {
             // Currently, there is no reason to preserve the span, so convert `Ident` to `String`.
@@ -101,8 +108,15 @@ impl Context {
 
         markers.push(marker.clone());
 
+        let asserts = args.iter().filter_map(assertion_trait).collect();
+        let mut builder = Builder::new(&span, asserts, inspect);
+        // `instrument` generates nothing if the `tracing` feature is disabled.
+        if instrument && cfg!(feature = "tracing") {
+            builder.tracing = Some(tracing_root(&crates));
+        }
+
         Ok(Self {
-            builder: Builder::new(&span),
+            builder,
             marker,
             markers,
             root,
@@ -112,6 +126,7 @@ impl Context {
             span,
             diagnostic,
             args,
+            crates,
             #[cfg(feature = "type_analysis")]
             traits: Vec::new(),
         })
@@ -124,13 +139,15 @@ impl Context {
 
     /// Make a new `Context` as a child based on a parent context `self`.
     pub(super) fn make_child(&mut self, span: TokenStream, args: TokenStream) -> Result<Self> {
-        Self::new(
+        let mut child = Self::new(
             span,
             args,
             false,
             mem::replace(&mut self.markers, Vec::new()),
             mem::replace(&mut self.diagnostic, Diagnostic::default()),
-        )
+        )?;
+        child.builder.function = self.builder.function.clone();
+        Ok(child)
     }
 
     /// Merge a child `Context` into a parent context `self`.
@@ -144,6 +161,11 @@ impl Context {
         }
     }
 
+    /// Sets the name of the function in which the branches are.
+    pub(super) fn set_function(&mut self, ident: &Ident) {
+        self.builder.function = Some(ident.to_string());
+    }
+
     pub(super) fn error(&mut self, message: Error) {
         match &mut self.diagnostic.message {
             Some(base) => base.combine(message),
@@ -245,7 +267,7 @@ impl Context {
 
     // build
 
-    pub(super) fn build(&mut self, f: impl FnOnce(ItemEnum)) -> Result<()> {
+    pub(super) fn build(&mut self, f: impl FnOnce(Vec<Item>)) -> Result<()> {
         fn err(cx: &Context) -> Error {
             let (msg1, msg2) = match cx.visit_last_mode {
                 VisitLastMode::Default => {
@@ -275,11 +297,11 @@ impl Context {
         if !self.builder.variants.is_empty() {
             #[cfg(not(feature = "type_analysis"))]
             {
-                f(self.builder.build(&self.args, &[]));
+                f(self.builder.build(&self.args, &[], &self.crates));
             }
             #[cfg(feature = "type_analysis")]
             {
-                f(self.builder.build(&self.args, &self.traits));
+                f(self.builder.build(&self.args, &self.traits, &self.crates));
             }
         }
         Ok(())
@@ -298,18 +320,26 @@ impl Context {
 
 mod kw {
     syn::custom_keyword!(marker);
+    syn::custom_keyword!(inspect);
+    syn::custom_keyword!(instrument);
 }
 
 #[allow(dead_code)] // false positive that fixed in Rust 1.39
 struct Args {
     args: Vec<Path>,
     marker: Option<Ident>,
+    crates: Vec<(Ident, Path)>,
+    inspect: bool,
+    instrument: bool,
 }
 
 impl Parse for Args {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         let mut args = Vec::new();
         let mut marker = None;
+        let mut crates = None;
+        let mut inspect = false;
+        let mut instrument = false;
         while !input.is_empty() {
             if input.peek(kw::marker) && input.peek2(Token![=]) {
                 let i: kw::marker = input.parse()?;
@@ -318,6 +348,23 @@ impl Parse for Args {
                 if marker.replace(ident).is_some() {
                     return Err(error!(i, "duplicate `marker` argument"));
                 }
+            } else if input.peek(Token![crate]) && input.peek2(token::Paren) {
+                let i: Token![crate] = input.parse()?;
+                let content;
+                let _ = parenthesized!(content in input);
+                if crates.replace(parse_crates(&content)?).is_some() {
+                    return Err(error!(i, "duplicate `crate` argument"));
+                }
+            } else if input.peek(kw::inspect) && !input.peek2(Token![::]) {
+                let i: kw::inspect = input.parse()?;
+                if mem::replace(&mut inspect, true) {
+                    return Err(error!(i, "duplicate `inspect` argument"));
+                }
+            } else if input.peek(kw::instrument) && !input.peek2(Token![::]) {
+                let i: kw::instrument = input.parse()?;
+                if mem::replace(&mut instrument, true) {
+                    return Err(error!(i, "duplicate `instrument` argument"));
+                }
             } else {
                 args.push(input.parse()?);
             }
@@ -328,7 +375,7 @@ impl Parse for Args {
             let _: Token![,] = input.parse()?;
         }
 
-        Ok(Self { args, marker })
+        Ok(Self { args, marker, crates: crates.unwrap_or_default(), inspect, instrument })
     }
 }
 
@@ -338,38 +385,193 @@ impl Parse for Args {
 struct Builder {
     ident: Ident,
     variants: Vec<Ident>,
+    /// Auto traits that each branch must implement.
+    asserts: Vec<TokenStream>,
+    /// The source of each branch, if the `inspect` argument is specified.
+    sources: Option<Vec<String>>,
+    /// The root path of the `tracing` crate, if the `instrument` argument is
+    /// specified and the `tracing` feature is enabled.
+    tracing: Option<TokenStream>,
+    /// The name of the function in which the branches are, if any.
+    function: Option<String>,
 }
 
 impl Builder {
-    fn new(input: &TokenStream) -> Self {
-        Self { ident: format_ident!("__Enum{}", hash(input)), variants: Vec::new() }
+    fn new(input: &TokenStream, asserts: Vec<TokenStream>, inspect: bool) -> Self {
+        Self {
+            ident: format_ident!("__Enum{}", hash(input)),
+            variants: Vec::new(),
+            asserts,
+            sources: if inspect { Some(Vec::new()) } else { None },
+            tracing: None,
+            function: None,
+        }
     }
 
     fn next_expr(&mut self, attrs: Vec<Attribute>, expr: Expr) -> Expr {
-        let variant = format_ident!("__Variant{}", self.variants.len());
+        let index = self.variants.len();
+        let variant = format_ident!("__Variant{}", index);
 
         let path =
             path(iter::once(self.ident.clone().into()).chain(iter::once(variant.clone().into())));
 
         self.variants.push(variant);
+        if let Some(sources) = &mut self.sources {
+            sources.push(source_text(&expr));
+        }
+
+        let expr = self.instrument_expr(index, expr);
+        expr_call(attrs, path, self.assert_expr(expr))
+    }
+
+    /// from `<expr>` into `match <expr> { x => { tracing::event!(..); x } }`
+    fn instrument_expr(&self, index: usize, expr: Expr) -> Expr {
+        let tracing = match &self.tracing {
+            Some(tracing) => tracing,
+            None => return expr,
+        };
 
-        expr_call(attrs, path, expr)
+        let function = self.function.as_ref().map(|function| quote!(function = #function,));
+        let source = source_text(&expr);
+        // Span the event (including the path to the macro) with the branch so
+        // that the file and line of the event's metadata are those of the
+        // branch, not of the attribute.
+        let span = expr.span();
+        let tracing: TokenStream = tracing
+            .clone()
+            .into_iter()
+            .map(|mut tt| {
+                tt.set_span(span);
+                tt
+            })
+            .collect();
+        let event = quote_spanned! {span=>
+            #tracing::event!(
+                #tracing::Level::TRACE,
+                #function
+                branch = #index,
+                source = #source,
+                "auto_enum branch selected"
+            );
+        };
+        parse_quote! {
+            match #expr {
+                __x => {
+                    #event
+                    __x
+                }
+            }
+        }
     }
 
-    fn build(&self, args: &[Path], traits: &[Path]) -> ItemEnum {
-        let derive = args.iter().chain(traits);
+    /// from `<expr>` into `match <expr> { x => { let _: &dyn <auto traits> = &x; x } }`
+    ///
+    /// The generated enum implements auto traits without deriving them, but if
+    /// one branch does not, the error only shows up where the enum is used.
+    /// Checking each branch makes the error point at the offending expression.
+    fn assert_expr(&self, expr: Expr) -> Expr {
+        if self.asserts.is_empty() {
+            return expr;
+        }
+
+        let asserts = &self.asserts;
+        // `match` (instead of `let`) keeps temporaries in `<expr>` alive as before.
+        let x = quote_spanned!(expr.span()=> &__x);
+        parse_quote! {
+            match #expr {
+                __x => {
+                    let _: &(dyn #(#asserts)+*) = #x;
+                    __x
+                }
+            }
+        }
+    }
+
+    fn build(&self, args: &[Path], traits: &[Path], crates: &[(Ident, Path)]) -> Vec<Item> {
+        let derive = args.iter().filter(|arg| assertion_trait(arg).is_none()).chain(traits);
+        let crates = if crates.is_empty() {
+            None
+        } else {
+            let crates = crates.iter().map(|(name, path)| quote!(#name = #path));
+            Some(quote!(crate(#(#crates),*)))
+        };
         let ident = &self.ident;
         let ty_generics = &self.variants;
         let variants = &self.variants;
         let fields = &self.variants;
 
-        parse_quote! {
+        let mut items = vec![parse_quote! {
             #[allow(non_camel_case_types)]
-            #[::auto_enums::enum_derive(#(#derive),*)]
+            #[::auto_enums::enum_derive(#(#derive,)* #crates)]
             enum #ident<#(#ty_generics),*> {
                 #(#variants(#fields),)*
             }
+        }];
+        if let Some(sources) = &self.sources {
+            items.push(self.build_inspect(sources));
         }
+        items
+    }
+
+    /// Generates the inherent items enabled by the `inspect` argument.
+    fn build_inspect(&self, sources: &[String]) -> Item {
+        let ident = &self.ident;
+        let ty_generics = &self.variants;
+        let variants = &self.variants;
+        let count = self.variants.len();
+        let indices = 0..count;
+
+        parse_quote! {
+            #[allow(dead_code)]
+            impl<#(#ty_generics),*> #ident<#(#ty_generics),*> {
+                /// The number of branches.
+                const VARIANT_COUNT: usize = #count;
+
+                /// Returns `VARIANT_COUNT`.
+                fn variant_count(&self) -> usize {
+                    Self::VARIANT_COUNT
+                }
+
+                /// Returns the index of the branch that produced this value.
+                fn variant_index(&self) -> usize {
+                    match self {
+                        #(#ident::#variants(_) => #indices,)*
+                    }
+                }
+
+                /// Returns the source of the branch that produced this value.
+                fn variant_source(&self) -> &'static str {
+                    match self {
+                        #(#ident::#variants(_) => #sources,)*
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the absolute path of the auto trait if `arg` is one of the traits
+/// checked on each branch instead of being passed to `#[enum_derive]`.
+fn assertion_trait(arg: &Path) -> Option<TokenStream> {
+    Some(match &*arg.to_token_stream().to_string().replace(" ", "") {
+        "Send" => quote!(::core::marker::Send),
+        "Sync" => quote!(::core::marker::Sync),
+        "Unpin" => quote!(::core::marker::Unpin),
+        // `core::panic::UnwindSafe` is stable since Rust 1.56.
+        #[cfg(stable_1_56)]
+        "UnwindSafe" => quote!(::core::panic::UnwindSafe),
+        #[cfg(all(feature = "std", not(stable_1_56)))]
+        "UnwindSafe" => quote!(::std::panic::UnwindSafe),
+        _ => return None,
+    })
+}
+
+/// Returns the root path of the `tracing` crate, taking `crate(tracing = <path>)`
+/// into account.
+fn tracing_root(crates: &[(Ident, Path)]) -> TokenStream {
+    match crates.iter().find(|(name, _)| name == "tracing") {
+        Some((_, path)) => path.to_token_stream(),
+        None => quote!(::tracing),
     }
 }
 
@@ -379,3 +581,19 @@ fn hash(input: &TokenStream) -> u64 {
     hasher.write(input.to_string().as_bytes());
     hasher.finish()
 }
+
+/// Returns the source text of `expr`, or its tokens if the source text is not
+/// available (e.g., the expression was generated by a macro).
+fn source_text(expr: &Expr) -> String {
+    let tokens = expr.to_token_stream();
+    // `Spanned::span` falls back to the span of the first token if spans cannot
+    // be joined, so join them here to detect it. `Span::join` always returns
+    // `None` on stable, so the source text is only used on nightly.
+    let mut iter = tokens.clone().into_iter().map(|tt| tt.span());
+    let span = match (iter.next(), iter.last()) {
+        (Some(first), Some(last)) => first.join(last),
+        (first, None) => first,
+        (None, Some(_)) => unreachable!(),
+    };
+    span.and_then(|span| span.source_text()).unwrap_or_else(|| tokens.to_string())
+}
@@ -4,13 +4,15 @@ mod expr;
 mod type_analysis;
 mod visitor;
 
+use std::{iter, mem};
+
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 #[cfg(feature = "type_analysis")]
 use syn::Pat;
 use syn::{
-    AngleBracketedGenericArguments, Expr, ExprClosure, GenericArgument, Item, ItemEnum, ItemFn,
-    Local, PathArguments, Result, ReturnType, Stmt, Type, TypePath,
+    AngleBracketedGenericArguments, Expr, ExprClosure, GenericArgument, Item, ItemFn, Local,
+    PathArguments, Result, ReturnType, Stmt, Type, TypePath,
 };
 
 use self::{
@@ -83,8 +85,12 @@ fn expand_expr(cx: &mut Context, expr: &mut Expr) -> Result<()> {
     Ok(())
 }
 
-fn build_expr(expr: &mut Expr, item: ItemEnum) {
-    replace_expr(expr, |expr| expr_block(block(vec![Stmt::Item(item.into()), Stmt::Expr(expr)])));
+fn build_expr(expr: &mut Expr, items: Vec<Item>) {
+    replace_expr(expr, |expr| {
+        expr_block(block(
+            items.into_iter().map(Stmt::Item).chain(iter::once(Stmt::Expr(expr))).collect(),
+        ))
+    });
 }
 
 // =================================================================================================
@@ -114,7 +120,7 @@ fn expand_parent_expr(cx: &mut Context, expr: &mut Expr, has_semi: bool) -> Resu
 
     expand_expr(cx, expr)?;
 
-    cx.build(|item| build_expr(expr, item))
+    cx.build(|items| build_expr(expr, items))
 }
 
 fn expand_parent_local(cx: &mut Context, local: &mut Local) -> Result<()> {
@@ -143,10 +149,12 @@ fn expand_parent_local(cx: &mut Context, local: &mut Local) -> Result<()> {
 
     expand_expr(cx, expr)?;
 
-    cx.build(|item| build_expr(expr, item))
+    cx.build(|items| build_expr(expr, items))
 }
 
 fn expand_parent_item_fn(cx: &mut Context, item: &mut ItemFn) -> Result<()> {
+    cx.set_function(&item.sig.ident);
+
     let ItemFn { sig, block, .. } = item;
     if let ReturnType::Type(_, ty) = &mut sig.output {
         match &**ty {
@@ -218,5 +226,8 @@ fn expand_parent_item_fn(cx: &mut Context, item: &mut ItemFn) -> Result<()> {
 
     cx.visitor(item);
 
-    cx.build(|i| item.block.stmts.insert(0, Stmt::Item(i.into())))
+    cx.build(|items| {
+        let stmts = mem::replace(&mut item.block.stmts, Vec::new());
+        item.block.stmts = items.into_iter().map(Stmt::Item).chain(stmts).collect();
+    })
 }
@@ -61,6 +61,10 @@ const TRAITS: &[&str] = &[
     "fmt::UpperExp",
     "fmt::UpperHex",
     "fmt::Write",
+    "Hasher",
+    "hash::Hasher",
+    "BuildHasher",
+    "hash::BuildHasher",
     "Iterator",
     "DoubleEndedIterator",
     "ExactSizeIterator",
@@ -75,8 +79,11 @@ const TRAITS: &[&str] = &[
     "Fn",
     "FnMut",
     "FnOnce",
+    "Coroutine",
     "Generator",
     "Future",
+    "IntoFuture",
+    "AsyncIterator",
     // std
     "Read",
     "io::Read",
@@ -88,4 +95,7 @@ const TRAITS: &[&str] = &[
     "io::Seek",
     "Error",
     "error::Error",
+    "AsFd",
+    "AsRawFd",
+    "IntoRawFd",
 ];
@@ -0,0 +1,20 @@
+pub(crate) mod async_iterator {
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["AsyncIterator"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        Ok(derive_trait(data, parse_quote!(::core::async_iter::AsyncIterator), None, parse_quote! {
+            trait AsyncIterator {
+                type Item;
+                #[inline]
+                fn poll_next(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                ) -> ::core::task::Poll<::core::option::Option<Self::Item>>;
+                #[inline]
+                fn size_hint(&self) -> (usize, ::core::option::Option<usize>);
+            }
+        }))
+    }
+}
@@ -0,0 +1,43 @@
+// `Borrow<T>` cannot be implemented for all `T` because it conflicts with
+// `impl<T> Borrow<T> for T`, so these require the borrowed type to be specified,
+// e.g., `Borrow<str>`.
+
+#[allow(clippy::module_inception)]
+pub(crate) mod borrow {
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["Borrow"];
+
+    pub(crate) fn derive_with_args(
+        data: &Data,
+        args: &syn::AngleBracketedGenericArguments,
+    ) -> Result<TokenStream> {
+        let ty = type_argument(args)?;
+        // Use turbofish because `trait_path` is also used in expressions.
+        Ok(derive_trait(data, parse_quote!(::core::borrow::Borrow::<#ty>), None, parse_quote! {
+            trait Borrow {
+                #[inline]
+                fn borrow(&self) -> &#ty;
+            }
+        }))
+    }
+}
+
+pub(crate) mod borrow_mut {
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["BorrowMut"];
+
+    pub(crate) fn derive_with_args(
+        data: &Data,
+        args: &syn::AngleBracketedGenericArguments,
+    ) -> Result<TokenStream> {
+        let ty = type_argument(args)?;
+        Ok(derive_trait(data, parse_quote!(::core::borrow::BorrowMut::<#ty>), None, parse_quote! {
+            trait BorrowMut {
+                #[inline]
+                fn borrow_mut(&mut self) -> &mut #ty;
+            }
+        }))
+    }
+}
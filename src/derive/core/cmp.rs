@@ -0,0 +1,90 @@
+pub(crate) mod transparent_partial_eq {
+    use derive_utils::EnumImpl;
+
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["transparent::PartialEq"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        // Values in the same variant are compared by the inner values, and values
+        // in different variants are never equal. Unlike `#[derive(PartialEq)]`,
+        // this bounds the field types instead of the type parameters.
+        let ident = &data.ident;
+        let mut impl_ = EnumImpl::new(data);
+
+        impl_.set_trait(parse_quote!(::core::cmp::PartialEq));
+        data.field_types().for_each(|f| {
+            impl_.push_where_predicate(parse_quote!(#f: ::core::cmp::PartialEq));
+        });
+
+        let eq = data
+            .variant_idents()
+            .map(|v| quote!((#ident::#v(x), #ident::#v(y)) => ::core::cmp::PartialEq::eq(x, y)));
+        let other = if data.variants.len() > 1 { Some(quote!(_ => false,)) } else { None };
+        impl_.push_item(parse_quote! {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                match (self, other) {
+                    #(#eq,)*
+                    #other
+                }
+            }
+        });
+
+        Ok(impl_.build())
+    }
+
+    /// Compares the values borrowed as `ty` if `Borrow<ty>` is also derived, so
+    /// that `x == y` if and only if `x.borrow() == y.borrow()` as `Borrow` requires,
+    /// even if `x` and `y` are different variants.
+    #[cfg(feature = "convert")]
+    pub(crate) fn derive_with_borrow(data: &Data, ty: &syn::Type) -> Result<TokenStream> {
+        let ident = &data.ident;
+        let mut impl_ = EnumImpl::new(data);
+
+        impl_.set_trait(parse_quote!(::core::cmp::PartialEq));
+        impl_.push_where_predicate(parse_quote!(#ty: ::core::cmp::PartialEq));
+        data.field_types().for_each(|f| {
+            impl_.push_where_predicate(parse_quote!(#f: ::core::borrow::Borrow<#ty>));
+        });
+
+        let borrow = data
+            .variant_idents()
+            .map(|v| quote!(#ident::#v(x) => ::core::borrow::Borrow::<#ty>::borrow(x)))
+            .collect::<Vec<_>>();
+        impl_.push_item(parse_quote! {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                let x: &#ty = match self { #(#borrow,)* };
+                let y: &#ty = match other { #(#borrow,)* };
+                ::core::cmp::PartialEq::eq(x, y)
+            }
+        });
+
+        Ok(impl_.build())
+    }
+}
+
+// `Eq` is usually derived by `#[derive(Eq)]`, which requires `PartialEq` for
+// the type parameters that implement `Eq`. If `transparent::PartialEq` compares
+// the borrowed values, it has different bounds, so `Eq` needs the same bounds.
+#[cfg(feature = "convert")]
+pub(crate) mod eq {
+    use derive_utils::EnumImpl;
+
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["Eq"];
+
+    pub(crate) fn derive_with_borrow(data: &Data, ty: &syn::Type) -> Result<TokenStream> {
+        let mut impl_ = EnumImpl::new(data);
+
+        impl_.set_trait(parse_quote!(::core::cmp::Eq));
+        impl_.push_where_predicate(parse_quote!(#ty: ::core::cmp::Eq));
+        data.field_types().for_each(|f| {
+            impl_.push_where_predicate(parse_quote!(#f: ::core::borrow::Borrow<#ty>));
+        });
+
+        Ok(impl_.build())
+    }
+}
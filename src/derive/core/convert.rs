@@ -11,6 +11,20 @@ pub(crate) mod as_ref {
             }
         }))
     }
+
+    /// Implements `AsRef` only for the specified type, e.g., `AsRef<str>`.
+    pub(crate) fn derive_with_args(
+        data: &Data,
+        args: &syn::AngleBracketedGenericArguments,
+    ) -> Result<TokenStream> {
+        let ty = type_argument(args)?;
+        Ok(derive_trait(data, parse_quote!(::core::convert::AsRef::<#ty>), None, parse_quote! {
+            trait AsRef {
+                #[inline]
+                fn as_ref(&self) -> &#ty;
+            }
+        }))
+    }
 }
 
 pub(crate) mod as_mut {
@@ -26,4 +40,18 @@ pub(crate) mod as_mut {
             }
         }))
     }
+
+    /// Implements `AsMut` only for the specified type, e.g., `AsMut<str>`.
+    pub(crate) fn derive_with_args(
+        data: &Data,
+        args: &syn::AngleBracketedGenericArguments,
+    ) -> Result<TokenStream> {
+        let ty = type_argument(args)?;
+        Ok(derive_trait(data, parse_quote!(::core::convert::AsMut::<#ty>), None, parse_quote! {
+            trait AsMut {
+                #[inline]
+                fn as_mut(&mut self) -> &mut #ty;
+            }
+        }))
+    }
 }
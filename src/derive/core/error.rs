@@ -0,0 +1,36 @@
+use derive_utils::EnumImpl;
+
+use crate::derive::*;
+
+pub(crate) const NAME: &[&str] = &["Error"];
+
+pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+    // `core::error::Error` is stable since Rust 1.81, and `std::error::Error`
+    // is a re-export of it, so it is always preferred when available.
+    #[cfg(stable_1_81)]
+    let error = quote!(::core::error::Error);
+    #[cfg(not(stable_1_81))]
+    let error = quote!(::std::error::Error);
+
+    let ident = &data.ident;
+    let source =
+        data.variant_idents().map(|v| quote!(#ident::#v(x) => ::core::option::Option::Some(x)));
+
+    let source = parse_quote! {
+        fn source(&self) -> ::core::option::Option<&(dyn (#error) + 'static)> {
+            match self { #(#source,)* }
+        }
+    };
+
+    let mut impl_ = EnumImpl::from_trait(data, parse_quote!(#error), None, parse_quote! {
+        trait Error {
+            #[allow(deprecated)]
+            fn description(&self) -> &str;
+        }
+    });
+
+    data.field_types().for_each(|f| impl_.push_where_predicate(parse_quote!(#f: 'static)));
+    impl_.push_item(source);
+
+    Ok(impl_.build())
+}
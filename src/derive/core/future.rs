@@ -14,3 +14,61 @@ pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
         }
     }))
 }
+
+#[cfg(stable_1_64)]
+pub(crate) mod into_future {
+    use derive_utils::EnumImpl;
+
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["IntoFuture"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        let ident = &data.ident;
+        let fst = data.field_types().next();
+        let mut impl_ = EnumImpl::new(data);
+
+        // `IntoFuture::IntoFuture` differs per variant, so this generates another
+        // enum that implements `Future` and uses it as the associated type.
+        let future = AssocEnum::new(
+            data,
+            "IntoFuture",
+            data.field_types()
+                .map(|f| parse_quote!(<#f as ::core::future::IntoFuture>::IntoFuture)),
+            super::derive,
+        )?;
+
+        impl_.set_trait(parse_quote!(::core::future::IntoFuture));
+        impl_.push_where_predicate(parse_quote!(#fst: ::core::future::IntoFuture));
+        data.field_types().skip(1).for_each(|f| {
+            impl_.push_where_predicate(parse_quote! {
+                #f: ::core::future::IntoFuture<
+                    Output = <#fst as ::core::future::IntoFuture>::Output,
+                >
+            })
+        });
+
+        let future_ident = &future.ident;
+        let future_ty = &future.ty;
+        let into_future = data.variant_idents().map(|v| {
+            quote!(#ident::#v(x) => #future_ident::#v(::core::future::IntoFuture::into_future(x)))
+        });
+
+        impl_.push_item(parse_quote! {
+            type Output = <#fst as ::core::future::IntoFuture>::Output;
+        });
+        impl_.push_item(parse_quote! {
+            type IntoFuture = #future_ty;
+        });
+        impl_.push_item(parse_quote! {
+            #[inline]
+            fn into_future(self) -> Self::IntoFuture {
+                match self { #(#into_future,)* }
+            }
+        });
+
+        let mut tokens = impl_.build();
+        tokens.extend(future.items);
+        Ok(tokens)
+    }
+}
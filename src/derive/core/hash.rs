@@ -0,0 +1,135 @@
+pub(crate) mod hasher {
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["Hasher", "hash::Hasher"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        Ok(derive_trait(data, parse_quote!(::core::hash::Hasher), None, parse_quote! {
+            trait Hasher {
+                #[inline]
+                fn finish(&self) -> u64;
+                #[inline]
+                fn write(&mut self, bytes: &[u8]);
+                #[inline]
+                fn write_u8(&mut self, i: u8);
+                #[inline]
+                fn write_u16(&mut self, i: u16);
+                #[inline]
+                fn write_u32(&mut self, i: u32);
+                #[inline]
+                fn write_u64(&mut self, i: u64);
+                #[inline]
+                fn write_u128(&mut self, i: u128);
+                #[inline]
+                fn write_usize(&mut self, i: usize);
+                #[inline]
+                fn write_i8(&mut self, i: i8);
+                #[inline]
+                fn write_i16(&mut self, i: i16);
+                #[inline]
+                fn write_i32(&mut self, i: i32);
+                #[inline]
+                fn write_i64(&mut self, i: i64);
+                #[inline]
+                fn write_i128(&mut self, i: i128);
+                #[inline]
+                fn write_isize(&mut self, i: isize);
+            }
+        }))
+    }
+}
+
+pub(crate) mod build_hasher {
+    use derive_utils::EnumImpl;
+
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["BuildHasher", "hash::BuildHasher"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        let ident = &data.ident;
+        let mut impl_ = EnumImpl::new(data);
+
+        // `BuildHasher::Hasher` differs per variant, so this generates another
+        // enum that implements `Hasher` and uses it as the associated type.
+        let hasher = AssocEnum::new(
+            data,
+            "Hasher",
+            data.field_types().map(|f| parse_quote!(<#f as ::core::hash::BuildHasher>::Hasher)),
+            super::hasher::derive,
+        )?;
+
+        impl_.set_trait(parse_quote!(::core::hash::BuildHasher));
+        data.field_types().for_each(|f| {
+            impl_.push_where_predicate(parse_quote!(#f: ::core::hash::BuildHasher));
+        });
+
+        let hasher_ident = &hasher.ident;
+        let hasher_ty = &hasher.ty;
+        let build_hasher = data.variant_idents().map(|v| {
+            quote!(#ident::#v(x) => #hasher_ident::#v(::core::hash::BuildHasher::build_hasher(x)))
+        });
+
+        impl_.push_item(parse_quote! {
+            type Hasher = #hasher_ty;
+        });
+        impl_.push_item(parse_quote! {
+            #[inline]
+            fn build_hasher(&self) -> Self::Hasher {
+                match self { #(#build_hasher,)* }
+            }
+        });
+
+        let mut tokens = impl_.build();
+        tokens.extend(hasher.items);
+        Ok(tokens)
+    }
+}
+
+pub(crate) mod transparent_hash {
+    #[cfg(feature = "convert")]
+    use derive_utils::EnumImpl;
+
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["transparent::Hash"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        // Unlike `#[derive(Hash)]`, this does not hash the discriminant, so the
+        // hash value is the same as the hash value of the inner value.
+        Ok(derive_trait(data, parse_quote!(::core::hash::Hash), None, parse_quote! {
+            trait Hash {
+                #[inline]
+                fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H);
+            }
+        }))
+    }
+    /// Hashes the value borrowed as `ty` if `Borrow<ty>` is also derived, so
+    /// that the hash value is the same as the hash value of the borrowed value
+    /// as `Borrow` requires.
+    #[cfg(feature = "convert")]
+    pub(crate) fn derive_with_borrow(data: &Data, ty: &syn::Type) -> Result<TokenStream> {
+        let ident = &data.ident;
+        let mut impl_ = EnumImpl::new(data);
+
+        impl_.set_trait(parse_quote!(::core::hash::Hash));
+        impl_.push_where_predicate(parse_quote!(#ty: ::core::hash::Hash));
+        data.field_types().for_each(|f| {
+            impl_.push_where_predicate(parse_quote!(#f: ::core::borrow::Borrow<#ty>));
+        });
+
+        let hash = data.variant_idents().map(|v| {
+            quote! {
+                #ident::#v(x) => ::core::hash::Hash::hash(::core::borrow::Borrow::<#ty>::borrow(x), state)
+            }
+        });
+        impl_.push_item(parse_quote! {
+            #[inline]
+            fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+                match self { #(#hash,)* }
+            }
+        });
+
+        Ok(impl_.build())
+    }
+}
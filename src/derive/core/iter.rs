@@ -1,25 +1,82 @@
+/// Unstable methods that are forwarded only if they are requested by the
+/// corresponding argument, e.g., `#[enum_derive(Iterator, iter::TryFold)]`.
+///
+/// The generated code requires `#![feature(...)]` in the crate that uses the
+/// derive, so this is an opt-in for each derive rather than a crate feature.
+#[derive(Default)]
+pub(crate) struct Unstable {
+    /// `iter::TryFold`: `Iterator::try_fold` and `DoubleEndedIterator::try_rfold`.
+    try_fold: bool,
+    /// `iter::AdvanceBy`: `Iterator::advance_by` and `DoubleEndedIterator::advance_back_by`.
+    advance_by: bool,
+    /// `iter::IsEmpty`: `ExactSizeIterator::is_empty`.
+    is_empty: bool,
+}
+
+impl Unstable {
+    /// Returns the derives that forward the methods requested by `name`, or
+    /// `None` if `name` is not an argument that requests unstable methods.
+    pub(crate) fn derives(name: &str) -> Option<&'static [&'static str]> {
+        Some(match name {
+            "iter::TryFold" | "iter::AdvanceBy" => &["Iterator", "DoubleEndedIterator"],
+            "iter::IsEmpty" => &["ExactSizeIterator"],
+            _ => return None,
+        })
+    }
+
+    pub(crate) fn set(&mut self, name: &str) {
+        match name {
+            "iter::TryFold" => self.try_fold = true,
+            "iter::AdvanceBy" => self.advance_by = true,
+            "iter::IsEmpty" => self.is_empty = true,
+            _ => unreachable!(),
+        }
+    }
+}
+
 pub(crate) mod iterator {
+    use super::Unstable;
     use crate::derive::*;
 
     pub(crate) const NAME: &[&str] = &["Iterator"];
 
-    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        // TODO: When `try_trait` stabilized, add `try_fold` and remove `fold`, `find` etc. conditionally.
+    pub(crate) fn derive(data: &Data, unstable: &Unstable) -> Result<TokenStream> {
+        // TODO: When `try_trait_v2` stabilized, add `try_fold` and remove `find` etc. conditionally.
 
-        // It is equally efficient if `try_fold` can be used.
-        let try_trait = quote! {
-            #[inline]
-            fn fold<__U, __F>(self, init: __U, f: __F) -> __U
-            where
-                __F: ::core::ops::FnMut(__U, Self::Item) -> __U;
-            #[inline]
-            fn find<__P>(&mut self, predicate: __P) -> ::core::option::Option<Self::Item>
-            where
-                __P: ::core::ops::FnMut(&Self::Item) -> bool;
-            #[inline]
-            fn find_map<__U, __F>(&mut self, f: __F) -> ::core::option::Option<__U>
-            where
-                __F: ::core::ops::FnMut(Self::Item) -> ::core::option::Option<__U>;
+        // `find`, `find_map`, `any`, `all`, etc. are implemented using `try_fold`
+        // by default, so it is equally efficient if `try_fold` can be used.
+        let try_trait = if unstable.try_fold {
+            quote! {
+                #[inline]
+                fn try_fold<__U, __F, __R>(&mut self, init: __U, f: __F) -> __R
+                where
+                    __F: ::core::ops::FnMut(__U, Self::Item) -> __R,
+                    __R: ::core::ops::Try<Output = __U>;
+            }
+        } else {
+            quote! {
+                #[inline]
+                fn find<__P>(&mut self, predicate: __P) -> ::core::option::Option<Self::Item>
+                where
+                    __P: ::core::ops::FnMut(&Self::Item) -> bool;
+                #[inline]
+                fn find_map<__U, __F>(&mut self, f: __F) -> ::core::option::Option<__U>
+                where
+                    __F: ::core::ops::FnMut(Self::Item) -> ::core::option::Option<__U>;
+            }
+        };
+
+        // TODO: When `iter_advance_by` stabilized, add `advance_by` conditionally.
+        let advance_by = if unstable.advance_by {
+            quote! {
+                #[inline]
+                fn advance_by(
+                    &mut self,
+                    n: usize,
+                ) -> ::core::result::Result<(), ::core::num::NonZero<usize>>;
+            }
+        } else {
+            TokenStream::new()
         };
 
         Ok(derive_trait(data, parse_quote!(::core::iter::Iterator), None, parse_quote! {
@@ -34,8 +91,15 @@ pub(crate) mod iterator {
                 #[inline]
                 fn last(self) -> ::core::option::Option<Self::Item>;
                 #[inline]
+                fn nth(&mut self, n: usize) -> ::core::option::Option<Self::Item>;
+                #advance_by
+                #[inline]
                 #[must_use = "if you really need to exhaust the iterator, consider `.for_each(drop)` instead"]
                 fn collect<__U: ::core::iter::FromIterator<Self::Item>>(self) -> __U;
+                #[inline]
+                fn fold<__U, __F>(self, init: __U, f: __F) -> __U
+                where
+                    __F: ::core::ops::FnMut(__U, Self::Item) -> __U;
                 #try_trait
             }
         }))
@@ -43,23 +107,52 @@ pub(crate) mod iterator {
 }
 
 pub(crate) mod double_ended_iterator {
+    use super::Unstable;
     use crate::derive::*;
 
     pub(crate) const NAME: &[&str] = &["DoubleEndedIterator"];
 
-    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        // TODO: When `try_trait` stabilized, add `try_rfold` and remove `rfold` and `rfind` conditionally.
+    pub(crate) fn derive(data: &Data, unstable: &Unstable) -> Result<TokenStream> {
+        // TODO: When `try_trait_v2` stabilized, add `try_rfold` and remove `rfind` conditionally.
 
-        // It is equally efficient if `try_rfold` can be used.
-        let try_trait = quote! {
-            #[inline]
-            fn rfold<__U, __F>(self, accum: __U, f: __F) -> __U
-            where
-                __F: ::core::ops::FnMut(__U, Self::Item) -> __U;
+        // `rfind`, `rposition`, etc. are implemented using `try_rfold` by default,
+        // so it is equally efficient if `try_rfold` can be used.
+        let try_trait = if unstable.try_fold {
+            quote! {
+                #[inline]
+                fn try_rfold<__U, __F, __R>(&mut self, init: __U, f: __F) -> __R
+                where
+                    __F: ::core::ops::FnMut(__U, Self::Item) -> __R,
+                    __R: ::core::ops::Try<Output = __U>;
+            }
+        } else {
+            quote! {
+                #[inline]
+                fn rfind<__P>(&mut self, predicate: __P) -> ::core::option::Option<Self::Item>
+                where
+                    __P: ::core::ops::FnMut(&Self::Item) -> bool;
+            }
+        };
+
+        // `nth_back` requires Rust 1.37.
+        #[cfg(stable_1_37)]
+        let nth_back = quote! {
             #[inline]
-            fn rfind<__P>(&mut self, predicate: __P) -> ::core::option::Option<Self::Item>
-            where
-                __P: ::core::ops::FnMut(&Self::Item) -> bool;
+            fn nth_back(&mut self, n: usize) -> ::core::option::Option<Self::Item>;
+        };
+        #[cfg(not(stable_1_37))]
+        let nth_back = TokenStream::new();
+        // TODO: When `iter_advance_by` stabilized, add `advance_back_by` conditionally.
+        let advance_back_by = if unstable.advance_by {
+            quote! {
+                #[inline]
+                fn advance_back_by(
+                    &mut self,
+                    n: usize,
+                ) -> ::core::result::Result<(), ::core::num::NonZero<usize>>;
+            }
+        } else {
+            TokenStream::new()
         };
 
         Ok(derive_trait(
@@ -70,6 +163,12 @@ pub(crate) mod double_ended_iterator {
                 trait DoubleEndedIterator: ::core::iter::Iterator {
                     #[inline]
                     fn next_back(&mut self) -> ::core::option::Option<Self::Item>;
+                    #nth_back
+                    #advance_back_by
+                    #[inline]
+                    fn rfold<__U, __F>(self, accum: __U, f: __F) -> __U
+                    where
+                        __F: ::core::ops::FnMut(__U, Self::Item) -> __U;
                     #try_trait
                 }
             },
@@ -78,12 +177,21 @@ pub(crate) mod double_ended_iterator {
 }
 
 pub(crate) mod exact_size_iterator {
+    use super::Unstable;
     use crate::derive::*;
 
     pub(crate) const NAME: &[&str] = &["ExactSizeIterator"];
 
-    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+    pub(crate) fn derive(data: &Data, unstable: &Unstable) -> Result<TokenStream> {
         // TODO: When `exact_size_is_empty` stabilized, add `is_empty` conditionally.
+        let is_empty = if unstable.is_empty {
+            quote! {
+                #[inline]
+                fn is_empty(&self) -> bool;
+            }
+        } else {
+            TokenStream::new()
+        };
 
         Ok(derive_trait(
             data,
@@ -93,6 +201,7 @@ pub(crate) mod exact_size_iterator {
                 trait ExactSizeIterator: ::core::iter::Iterator {
                     #[inline]
                     fn len(&self) -> usize;
+                    #is_empty
                 }
             },
         ))
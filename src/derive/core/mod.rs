@@ -1,10 +1,23 @@
+/// `core::async_iter`
+#[cfg(feature = "async_iterator")]
+pub(crate) mod async_iter;
+/// `core::borrow`
+#[cfg(feature = "convert")]
+pub(crate) mod borrow;
+/// `core::cmp`
+pub(crate) mod cmp;
 /// `core::convert`
 #[cfg(feature = "convert")]
 pub(crate) mod convert;
+/// `core::error`
+#[cfg(any(feature = "std", stable_1_81))]
+pub(crate) mod error;
 /// `core::fmt`
 pub(crate) mod fmt;
 /// `core::future`
 pub(crate) mod future;
+/// `core::hash`
+pub(crate) mod hash;
 /// `core::iter`
 pub(crate) mod iter;
 /// `core::ops`
@@ -92,22 +92,30 @@ pub(crate) mod range_bounds {
     }
 }
 
-#[cfg(feature = "generator_trait")]
-pub(crate) mod generator {
+// `Generator` and `GeneratorState` were renamed to `Coroutine` and `CoroutineState`
+// in Rust 1.75 nightly. `Generator` is an alias of `Coroutine`, and both
+// derives implement the trait that exists on the current compiler.
+#[cfg(any(feature = "coroutine_trait", feature = "generator_trait"))]
+pub(crate) mod coroutine {
     use crate::derive::*;
 
-    pub(crate) const NAME: &[&str] = &["Generator"];
+    pub(crate) const NAME: &[&str] = &["Coroutine", "Generator"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::core::ops::Generator), None, parse_quote! {
-            trait Generator<R> {
+        #[cfg(coroutine_trait_renamed)]
+        let (trait_, state) = (quote!(::core::ops::Coroutine), quote!(::core::ops::CoroutineState));
+        #[cfg(not(coroutine_trait_renamed))]
+        let (trait_, state) = (quote!(::core::ops::Generator), quote!(::core::ops::GeneratorState));
+
+        Ok(derive_trait(data, parse_quote!(#trait_), None, parse_quote! {
+            trait Coroutine<R> {
                 type Yield;
                 type Return;
                 #[inline]
                 fn resume(
                     self: ::core::pin::Pin<&mut Self>,
                     arg: R,
-                ) -> ::core::ops::GeneratorState<Self::Yield, Self::Return>;
+                ) -> #state<Self::Yield, Self::Return>;
             }
         }))
     }
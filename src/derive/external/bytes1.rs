@@ -0,0 +1,88 @@
+pub(crate) mod buf {
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["bytes1::Buf"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        let bytes = data.crate_root("bytes");
+        // `Buf::chunks_vectored` is only available when the `std` feature of
+        // `bytes` is enabled.
+        #[cfg(feature = "std")]
+        let chunks_vectored = quote! {
+            #[inline]
+            fn chunks_vectored<'__a>(&'__a self, dst: &mut [::std::io::IoSlice<'__a>]) -> usize;
+        };
+        #[cfg(not(feature = "std"))]
+        let chunks_vectored = TokenStream::new();
+
+        Ok(derive_trait(data, parse_quote!(#bytes::Buf), None, parse_quote! {
+            trait Buf {
+                #[inline]
+                fn remaining(&self) -> usize;
+                #[inline]
+                fn chunk(&self) -> &[u8];
+                #chunks_vectored
+                #[inline]
+                fn advance(&mut self, cnt: usize);
+                #[inline]
+                fn has_remaining(&self) -> bool;
+                #[inline]
+                fn copy_to_slice(&mut self, dst: &mut [u8]);
+                #[inline]
+                fn copy_to_bytes(&mut self, len: usize) -> #bytes::Bytes;
+            }
+        }))
+    }
+}
+
+pub(crate) mod buf_mut {
+    use derive_utils::EnumImpl;
+
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["bytes1::BufMut"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        let bytes = data.crate_root("bytes");
+        let ident = &data.ident;
+        let mut impl_ =
+            EnumImpl::from_trait(data, parse_quote!(#bytes::BufMut), None, parse_quote! {
+                unsafe trait BufMut {
+                    #[inline]
+                    fn remaining_mut(&self) -> usize;
+                    #[inline]
+                    fn has_remaining_mut(&self) -> bool;
+                    #[inline]
+                    fn chunk_mut(&mut self) -> &mut #bytes::buf::UninitSlice;
+                    #[inline]
+                    fn put<__T>(&mut self, src: __T)
+                    where
+                        __T: #bytes::Buf,
+                        Self: ::core::marker::Sized;
+                    #[inline]
+                    fn put_slice(&mut self, src: &[u8]);
+                }
+            });
+
+        let advance_mut = data
+            .variant_idents()
+            .map(|v| quote!(#ident::#v(x) => #bytes::BufMut::advance_mut(x, cnt)));
+        impl_.push_item(parse_quote! {
+            #[inline]
+            #[allow(unused_unsafe)]
+            unsafe fn advance_mut(&mut self, cnt: usize) {
+                // SAFETY: the caller must uphold the safety contract for `advance_mut`,
+                // and `self` always forwards to the same inner buffer.
+                unsafe {
+                    match self { #(#advance_mut,)* }
+                }
+            }
+        });
+
+        // `BufMut` is an unsafe trait. Implementing it is sound because every
+        // method forwards to the same variant's implementation.
+        let mut impl_ = impl_.build_impl();
+        impl_.attrs.push(parse_quote!(#[allow(unsafe_code)]));
+        Ok(impl_.into_token_stream())
+    }
+}
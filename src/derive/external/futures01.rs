@@ -4,12 +4,13 @@ pub(crate) mod future {
     pub(crate) const NAME: &[&str] = &["futures01::Future"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::futures::future::Future), None, parse_quote! {
+        let futures = data.crate_root("futures");
+        Ok(derive_trait(data, parse_quote!(#futures::future::Future), None, parse_quote! {
             trait Future {
                 type Item;
                 type Error;
                 #[inline]
-                fn poll(&mut self) -> ::futures::Poll<Self::Item, Self::Error>;
+                fn poll(&mut self) -> #futures::Poll<Self::Item, Self::Error>;
             }
         }))
     }
@@ -21,14 +22,15 @@ pub(crate) mod stream {
     pub(crate) const NAME: &[&str] = &["futures01::Stream"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::futures::stream::Stream), None, parse_quote! {
+        let futures = data.crate_root("futures");
+        Ok(derive_trait(data, parse_quote!(#futures::stream::Stream), None, parse_quote! {
             trait Stream {
                 type Item;
                 type Error;
                 #[inline]
                 fn poll(
                     &mut self,
-                ) -> ::futures::Poll<::core::option::Option<Self::Item>, Self::Error>;
+                ) -> #futures::Poll<::core::option::Option<Self::Item>, Self::Error>;
             }
         }))
     }
@@ -40,7 +42,8 @@ pub(crate) mod sink {
     pub(crate) const NAME: &[&str] = &["futures01::Sink"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::futures::sink::Sink), None, parse_quote! {
+        let futures = data.crate_root("futures");
+        Ok(derive_trait(data, parse_quote!(#futures::sink::Sink), None, parse_quote! {
             trait Sink {
                 type SinkItem;
                 type SinkError;
@@ -48,11 +51,11 @@ pub(crate) mod sink {
                 fn start_send(
                     &mut self,
                     item: Self::SinkItem,
-                ) -> ::futures::StartSend<Self::SinkItem, Self::SinkError>;
+                ) -> #futures::StartSend<Self::SinkItem, Self::SinkError>;
                 #[inline]
-                fn poll_complete(&mut self) -> ::futures::Poll<(), Self::SinkError>;
+                fn poll_complete(&mut self) -> #futures::Poll<(), Self::SinkError>;
                 #[inline]
-                fn close(&mut self) -> ::futures::Poll<(), Self::SinkError>;
+                fn close(&mut self) -> #futures::Poll<(), Self::SinkError>;
             }
         }))
     }
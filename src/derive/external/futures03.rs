@@ -4,7 +4,8 @@ pub(crate) mod async_buf_read {
     pub(crate) const NAME: &[&str] = &["futures03::AsyncBufRead"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::futures::io::AsyncBufRead), None, parse_quote! {
+        let futures = data.crate_root("futures");
+        Ok(derive_trait(data, parse_quote!(#futures::io::AsyncBufRead), None, parse_quote! {
             trait AsyncBufRead {
                 #[inline]
                 fn poll_fill_buf<'__a>(
@@ -24,7 +25,8 @@ pub(crate) mod async_read {
     pub(crate) const NAME: &[&str] = &["futures03::AsyncRead"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::futures::io::AsyncRead), None, parse_quote! {
+        let futures = data.crate_root("futures");
+        Ok(derive_trait(data, parse_quote!(#futures::io::AsyncRead), None, parse_quote! {
             trait AsyncRead {
                 #[inline]
                 fn poll_read(
@@ -49,7 +51,8 @@ pub(crate) mod async_seek {
     pub(crate) const NAME: &[&str] = &["futures03::AsyncSeek"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::futures::io::AsyncSeek), None, parse_quote! {
+        let futures = data.crate_root("futures");
+        Ok(derive_trait(data, parse_quote!(#futures::io::AsyncSeek), None, parse_quote! {
             trait AsyncSeek {
                 #[inline]
                 fn poll_seek(
@@ -68,7 +71,8 @@ pub(crate) mod async_write {
     pub(crate) const NAME: &[&str] = &["futures03::AsyncWrite"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::futures::io::AsyncWrite), None, parse_quote! {
+        let futures = data.crate_root("futures");
+        Ok(derive_trait(data, parse_quote!(#futures::io::AsyncWrite), None, parse_quote! {
             trait AsyncWrite {
                 #[inline]
                 fn poll_write(
@@ -97,13 +101,56 @@ pub(crate) mod async_write {
     }
 }
 
+pub(crate) mod fused_future {
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["futures03::FusedFuture"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        let futures = data.crate_root("futures");
+        Ok(derive_trait(
+            data,
+            parse_quote!(#futures::future::FusedFuture),
+            Some(format_ident!("Output")),
+            parse_quote! {
+                trait FusedFuture: ::core::future::Future {
+                    #[inline]
+                    fn is_terminated(&self) -> bool;
+                }
+            },
+        ))
+    }
+}
+
+pub(crate) mod fused_stream {
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["futures03::FusedStream"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        let futures = data.crate_root("futures");
+        Ok(derive_trait(
+            data,
+            parse_quote!(#futures::stream::FusedStream),
+            Some(format_ident!("Item")),
+            parse_quote! {
+                trait FusedStream: #futures::stream::Stream {
+                    #[inline]
+                    fn is_terminated(&self) -> bool;
+                }
+            },
+        ))
+    }
+}
+
 pub(crate) mod sink {
     use crate::derive::*;
 
     pub(crate) const NAME: &[&str] = &["futures03::Sink"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::futures::sink::Sink), None, parse_quote! {
+        let futures = data.crate_root("futures");
+        Ok(derive_trait(data, parse_quote!(#futures::sink::Sink), None, parse_quote! {
             trait Sink<Item> {
                 type Error;
                 #[inline]
@@ -137,7 +184,8 @@ pub(crate) mod stream {
     pub(crate) const NAME: &[&str] = &["futures03::Stream"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::futures::stream::Stream), None, parse_quote! {
+        let futures = data.crate_root("futures");
+        Ok(derive_trait(data, parse_quote!(#futures::stream::Stream), None, parse_quote! {
             trait Stream {
                 type Item;
                 #[inline]
@@ -0,0 +1,28 @@
+pub(crate) mod body {
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["http_body1::Body"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        let http_body = data.crate_root("http_body");
+        Ok(derive_trait(data, parse_quote!(#http_body::Body), None, parse_quote! {
+            trait Body {
+                type Data;
+                type Error;
+                #[inline]
+                fn poll_frame(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                ) -> ::core::task::Poll<
+                    ::core::option::Option<
+                        ::core::result::Result<#http_body::Frame<Self::Data>, Self::Error>,
+                    >,
+                >;
+                #[inline]
+                fn is_end_stream(&self) -> bool;
+                #[inline]
+                fn size_hint(&self) -> #http_body::SizeHint;
+            }
+        }))
+    }
+}
@@ -0,0 +1,17 @@
+use crate::derive::*;
+
+pub(crate) const NAME: &[&str] = &["log::Log"];
+
+pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+    let log = data.crate_root("log");
+    Ok(derive_trait(data, parse_quote!(#log::Log), None, parse_quote! {
+        trait Log {
+            #[inline]
+            fn enabled(&self, metadata: &#log::Metadata<'_>) -> bool;
+            #[inline]
+            fn log(&self, record: &#log::Record<'_>);
+            #[inline]
+            fn flush(&self);
+        }
+    }))
+}
@@ -1,9 +1,21 @@
+// https://docs.rs/bytes/1
+#[cfg(feature = "bytes1")]
+pub(crate) mod bytes1;
 // https://docs.rs/futures/0.1
 #[cfg(feature = "futures01")]
 pub(crate) mod futures01;
 // https://docs.rs/futures/0.3
 #[cfg(feature = "futures03")]
 pub(crate) mod futures03;
+// https://docs.rs/http-body/1
+#[cfg(feature = "http_body1")]
+pub(crate) mod http_body1;
+// https://docs.rs/log/0.4
+#[cfg(feature = "log")]
+pub(crate) mod log;
+// https://docs.rs/rand_core/0.6
+#[cfg(feature = "rand_core06")]
+pub(crate) mod rand_core06;
 // https://docs.rs/rayon/1
 #[cfg(feature = "rayon")]
 pub(crate) mod rayon;
@@ -22,3 +34,12 @@ pub(crate) mod tokio03;
 // https://docs.rs/tokio/1
 #[cfg(feature = "tokio1")]
 pub(crate) mod tokio1;
+// https://docs.rs/tower-service/0.3
+#[cfg(feature = "tower")]
+pub(crate) mod tower;
+// https://docs.rs/tracing-core/0.1
+#[cfg(feature = "tracing_core")]
+pub(crate) mod tracing_core;
+// https://docs.rs/tracing-subscriber/0.3
+#[cfg(feature = "tracing_subscriber")]
+pub(crate) mod tracing_subscriber;
@@ -0,0 +1,39 @@
+pub(crate) mod rng_core {
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["rand_core06::RngCore"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        let rand_core = data.crate_root("rand_core");
+        Ok(derive_trait(data, parse_quote!(#rand_core::RngCore), None, parse_quote! {
+            trait RngCore {
+                #[inline]
+                fn next_u32(&mut self) -> u32;
+                #[inline]
+                fn next_u64(&mut self) -> u64;
+                #[inline]
+                fn fill_bytes(&mut self, dest: &mut [u8]);
+                #[inline]
+                fn try_fill_bytes(
+                    &mut self,
+                    dest: &mut [u8],
+                ) -> ::core::result::Result<(), #rand_core::Error>;
+            }
+        }))
+    }
+}
+
+pub(crate) mod crypto_rng {
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["rand_core06::CryptoRng"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        let rand_core = data.crate_root("rand_core");
+        // `CryptoRng` is a marker trait, so the generated impl only has
+        // `where` clauses that require all variants to implement it.
+        Ok(derive_trait(data, parse_quote!(#rand_core::CryptoRng), None, parse_quote! {
+            trait CryptoRng {}
+        }))
+    }
+}
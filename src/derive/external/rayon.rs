@@ -4,13 +4,14 @@ pub(crate) mod par_iter {
     pub(crate) const NAME: &[&str] = &["rayon::ParallelIterator"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::rayon::iter::ParallelIterator), None, parse_quote! {
+        let rayon = data.crate_root("rayon");
+        Ok(derive_trait(data, parse_quote!(#rayon::iter::ParallelIterator), None, parse_quote! {
             trait ParallelIterator {
                 type Item;
                 #[inline]
                 fn drive_unindexed<__C>(self, consumer: __C) -> __C::Result
                 where
-                    __C: ::rayon::iter::plumbing::UnindexedConsumer<Self::Item>;
+                    __C: #rayon::iter::plumbing::UnindexedConsumer<Self::Item>;
                 #[inline]
                 fn opt_len(&self) -> ::core::option::Option<usize>;
             }
@@ -24,22 +25,23 @@ pub(crate) mod indexed_par_iter {
     pub(crate) const NAME: &[&str] = &["rayon::IndexedParallelIterator"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        let rayon = data.crate_root("rayon");
         Ok(derive_trait(
             data,
-            parse_quote!(::rayon::iter::IndexedParallelIterator),
+            parse_quote!(#rayon::iter::IndexedParallelIterator),
             Some(format_ident!("Item")),
             parse_quote! {
-                trait IndexedParallelIterator: ::rayon::iter::ParallelIterator {
+                trait IndexedParallelIterator: #rayon::iter::ParallelIterator {
                     #[inline]
                     fn drive<__C>(self, consumer: __C) -> __C::Result
                     where
-                        __C: ::rayon::iter::plumbing::Consumer<Self::Item>;
+                        __C: #rayon::iter::plumbing::Consumer<Self::Item>;
                     #[inline]
                     fn len(&self) -> usize;
                     #[inline]
                     fn with_producer<__CB>(self, callback: __CB) -> __CB::Output
                     where
-                        __CB: ::rayon::iter::plumbing::ProducerCallback<Self::Item>;
+                        __CB: #rayon::iter::plumbing::ProducerCallback<Self::Item>;
                 }
             },
         ))
@@ -52,12 +54,13 @@ pub(crate) mod par_extend {
     pub(crate) const NAME: &[&str] = &["rayon::ParallelExtend"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::rayon::iter::ParallelExtend), None, parse_quote! {
+        let rayon = data.crate_root("rayon");
+        Ok(derive_trait(data, parse_quote!(#rayon::iter::ParallelExtend), None, parse_quote! {
             trait ParallelExtend<__T: Send> {
                 #[inline]
                 fn par_extend<__I>(&mut self, par_iter: __I)
                 where
-                    __I: ::rayon::iter::IntoParallelIterator<Item = __T>;
+                    __I: #rayon::iter::IntoParallelIterator<Item = __T>;
             }
         }))
     }
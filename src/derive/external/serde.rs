@@ -4,7 +4,8 @@ pub(crate) mod serialize {
     pub(crate) const NAME: &[&str] = &["serde::Serialize"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::serde::ser::Serialize), None, parse_quote! {
+        let serde = data.crate_root("serde");
+        Ok(derive_trait(data, parse_quote!(#serde::ser::Serialize), None, parse_quote! {
             trait Serialize {
                 #[inline]
                 fn serialize<__S>(
@@ -12,8 +13,649 @@ pub(crate) mod serialize {
                     serializer: __S,
                 ) -> ::core::result::Result<__S::Ok, __S::Error>
                 where
-                    __S: ::serde::ser::Serializer;
+                    __S: #serde::ser::Serializer;
             }
         }))
     }
 }
+
+pub(crate) mod serializer {
+    use derive_utils::EnumImpl;
+
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["serde::Serializer"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        let serde = data.crate_root("serde");
+        let ident = &data.ident;
+        let mut impl_ = EnumImpl::from_trait(
+            data,
+            parse_quote!(#serde::ser::Serializer),
+            None,
+            parse_quote! {
+                trait Serializer {
+                    type Ok;
+                    type Error;
+                    #[inline]
+                    fn serialize_bool(
+                        self,
+                        v: bool,
+                    ) -> ::core::result::Result<Self::Ok, Self::Error>;
+                    #[inline]
+                    fn serialize_i8(self, v: i8) -> ::core::result::Result<Self::Ok, Self::Error>;
+                    #[inline]
+                    fn serialize_i16(self, v: i16) -> ::core::result::Result<Self::Ok, Self::Error>;
+                    #[inline]
+                    fn serialize_i32(self, v: i32) -> ::core::result::Result<Self::Ok, Self::Error>;
+                    #[inline]
+                    fn serialize_i64(self, v: i64) -> ::core::result::Result<Self::Ok, Self::Error>;
+                    #[inline]
+                    fn serialize_i128(
+                        self,
+                        v: i128,
+                    ) -> ::core::result::Result<Self::Ok, Self::Error>;
+                    #[inline]
+                    fn serialize_u8(self, v: u8) -> ::core::result::Result<Self::Ok, Self::Error>;
+                    #[inline]
+                    fn serialize_u16(self, v: u16) -> ::core::result::Result<Self::Ok, Self::Error>;
+                    #[inline]
+                    fn serialize_u32(self, v: u32) -> ::core::result::Result<Self::Ok, Self::Error>;
+                    #[inline]
+                    fn serialize_u64(self, v: u64) -> ::core::result::Result<Self::Ok, Self::Error>;
+                    #[inline]
+                    fn serialize_u128(
+                        self,
+                        v: u128,
+                    ) -> ::core::result::Result<Self::Ok, Self::Error>;
+                    #[inline]
+                    fn serialize_f32(self, v: f32) -> ::core::result::Result<Self::Ok, Self::Error>;
+                    #[inline]
+                    fn serialize_f64(self, v: f64) -> ::core::result::Result<Self::Ok, Self::Error>;
+                    #[inline]
+                    fn serialize_char(
+                        self,
+                        v: char,
+                    ) -> ::core::result::Result<Self::Ok, Self::Error>;
+                    #[inline]
+                    fn serialize_str(
+                        self,
+                        v: &str,
+                    ) -> ::core::result::Result<Self::Ok, Self::Error>;
+                    #[inline]
+                    fn serialize_bytes(
+                        self,
+                        v: &[u8],
+                    ) -> ::core::result::Result<Self::Ok, Self::Error>;
+                    #[inline]
+                    fn serialize_none(self) -> ::core::result::Result<Self::Ok, Self::Error>;
+                    #[inline]
+                    fn serialize_some<__T>(
+                        self,
+                        value: &__T,
+                    ) -> ::core::result::Result<Self::Ok, Self::Error>
+                    where
+                        __T: ?Sized + #serde::ser::Serialize;
+                    #[inline]
+                    fn serialize_unit(self) -> ::core::result::Result<Self::Ok, Self::Error>;
+                    #[inline]
+                    fn serialize_unit_struct(
+                        self,
+                        name: &'static str,
+                    ) -> ::core::result::Result<Self::Ok, Self::Error>;
+                    #[inline]
+                    fn serialize_unit_variant(
+                        self,
+                        name: &'static str,
+                        variant_index: u32,
+                        variant: &'static str,
+                    ) -> ::core::result::Result<Self::Ok, Self::Error>;
+                    #[inline]
+                    fn serialize_newtype_struct<__T>(
+                        self,
+                        name: &'static str,
+                        value: &__T,
+                    ) -> ::core::result::Result<Self::Ok, Self::Error>
+                    where
+                        __T: ?Sized + #serde::ser::Serialize;
+                    #[inline]
+                    fn serialize_newtype_variant<__T>(
+                        self,
+                        name: &'static str,
+                        variant_index: u32,
+                        variant: &'static str,
+                        value: &__T,
+                    ) -> ::core::result::Result<Self::Ok, Self::Error>
+                    where
+                        __T: ?Sized + #serde::ser::Serialize;
+                    #[inline]
+                    fn collect_str<__T>(
+                        self,
+                        value: &__T,
+                    ) -> ::core::result::Result<Self::Ok, Self::Error>
+                    where
+                        __T: ?Sized + ::core::fmt::Display;
+                    #[inline]
+                    fn is_human_readable(&self) -> bool;
+                }
+            },
+        );
+
+        let serialize_element = quote! {
+            #[inline]
+            fn serialize_element<__T>(
+                &mut self,
+                value: &__T,
+            ) -> ::core::result::Result<(), Self::Error>
+            where
+                __T: ?Sized + #serde::ser::Serialize;
+        };
+        let serialize_field = quote! {
+            #[inline]
+            fn serialize_field<__T>(
+                &mut self,
+                value: &__T,
+            ) -> ::core::result::Result<(), Self::Error>
+            where
+                __T: ?Sized + #serde::ser::Serialize;
+        };
+        let serialize_struct_field = quote! {
+            #[inline]
+            fn serialize_field<__T>(
+                &mut self,
+                key: &'static str,
+                value: &__T,
+            ) -> ::core::result::Result<(), Self::Error>
+            where
+                __T: ?Sized + #serde::ser::Serialize;
+            #[inline]
+            fn skip_field(&mut self, key: &'static str) -> ::core::result::Result<(), Self::Error>;
+        };
+        let serialize_entry = quote! {
+            #[inline]
+            fn serialize_key<__T>(&mut self, key: &__T) -> ::core::result::Result<(), Self::Error>
+            where
+                __T: ?Sized + #serde::ser::Serialize;
+            #[inline]
+            fn serialize_value<__T>(
+                &mut self,
+                value: &__T,
+            ) -> ::core::result::Result<(), Self::Error>
+            where
+                __T: ?Sized + #serde::ser::Serialize;
+            #[inline]
+            fn serialize_entry<__K, __V>(
+                &mut self,
+                key: &__K,
+                value: &__V,
+            ) -> ::core::result::Result<(), Self::Error>
+            where
+                __K: ?Sized + #serde::ser::Serialize,
+                __V: ?Sized + #serde::ser::Serialize;
+        };
+
+        let name = quote!(name: &'static str);
+        let variant = quote!(variant_index: u32, variant: &'static str);
+        // (associated type, method, method parameters, method arguments, trait items)
+        let compound = vec![
+            (
+                "SerializeSeq",
+                "serialize_seq",
+                quote!(len: ::core::option::Option<usize>),
+                quote!(len),
+                serialize_element.clone(),
+            ),
+            (
+                "SerializeTuple",
+                "serialize_tuple",
+                quote!(len: usize),
+                quote!(len),
+                serialize_element,
+            ),
+            (
+                "SerializeTupleStruct",
+                "serialize_tuple_struct",
+                quote!(#name, len: usize),
+                quote!(name, len),
+                serialize_field.clone(),
+            ),
+            (
+                "SerializeTupleVariant",
+                "serialize_tuple_variant",
+                quote!(#name, #variant, len: usize),
+                quote!(name, variant_index, variant, len),
+                serialize_field,
+            ),
+            (
+                "SerializeMap",
+                "serialize_map",
+                quote!(len: ::core::option::Option<usize>),
+                quote!(len),
+                serialize_entry,
+            ),
+            (
+                "SerializeStruct",
+                "serialize_struct",
+                quote!(#name, len: usize),
+                quote!(name, len),
+                serialize_struct_field.clone(),
+            ),
+            (
+                "SerializeStructVariant",
+                "serialize_struct_variant",
+                quote!(#name, #variant, len: usize),
+                quote!(name, variant_index, variant, len),
+                serialize_struct_field,
+            ),
+        ];
+
+        // Each `Serialize*` associated type differs per variant, so this generates
+        // an enum that implements the corresponding trait for each of them.
+        let mut items = TokenStream::new();
+        for (assoc, method, params, args, trait_items) in compound {
+            let assoc_ident = format_ident!("{}", assoc);
+            let method = format_ident!("{}", method);
+            let assoc = AssocEnum::new(
+                data,
+                assoc,
+                data.field_types()
+                    .map(|f| parse_quote!(<#f as #serde::ser::Serializer>::#assoc_ident)),
+                |data| {
+                    Ok(derive_trait(
+                        data,
+                        parse_quote!(#serde::ser::#assoc_ident),
+                        None,
+                        parse_quote! {
+                            trait #assoc_ident {
+                                type Ok;
+                                type Error;
+                                #trait_items
+                                #[inline]
+                                fn end(self) -> ::core::result::Result<Self::Ok, Self::Error>;
+                            }
+                        },
+                    ))
+                },
+            )?;
+
+            let assoc_enum = &assoc.ident;
+            let assoc_ty = &assoc.ty;
+            let arms = data.variant_idents().map(|v| {
+                quote! {
+                    #ident::#v(x) => ::core::result::Result::map(
+                        #serde::ser::Serializer::#method(x, #args),
+                        #assoc_enum::#v,
+                    )
+                }
+            });
+
+            impl_.push_item(parse_quote! {
+                type #assoc_ident = #assoc_ty;
+            });
+            impl_.push_item(parse_quote! {
+                #[inline]
+                fn #method(
+                    self,
+                    #params,
+                ) -> ::core::result::Result<Self::#assoc_ident, Self::Error> {
+                    match self { #(#arms,)* }
+                }
+            });
+            items.extend(assoc.items);
+        }
+
+        let mut tokens = impl_.build();
+        tokens.extend(items);
+        Ok(tokens)
+    }
+}
+
+pub(crate) mod deserialize {
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["serde::Deserialize"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        let serde = data.crate_root("serde");
+        let ident = &data.ident;
+        let name = ident.to_string();
+
+        let mut generics = data.generics.clone();
+        generics.params.insert(0, parse_quote!('__de));
+        {
+            let where_clause = generics.make_where_clause();
+            data.field_types().for_each(|f| {
+                where_clause.predicates.push(parse_quote!(#f: #serde::de::Deserialize<'__de>));
+            });
+        }
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        let ty_generics = data.generics.split_for_impl().1;
+
+        // Like `#[serde(untagged)]`, the input is buffered into `__Content` and
+        // each variant is tried in declaration order.
+        let variants = data.variant_idents().zip(data.field_types()).map(|(v, f)| {
+            let v_name = v.to_string();
+            quote! {
+                match <#f as #serde::de::Deserialize<'__de>>::deserialize(
+                    __ContentRefDeserializer::<__D::Error>::new(&__content),
+                ) {
+                    ::core::result::Result::Ok(x) => {
+                        return ::core::result::Result::Ok(#ident::#v(x));
+                    }
+                    ::core::result::Result::Err(e) => {
+                        let _ = ::core::fmt::Write::write_fmt(
+                            &mut __errors,
+                            format_args!("\n    variant `{}`: {}", #v_name, e),
+                        );
+                    }
+                }
+            }
+        });
+        let content = content(&serde);
+
+        Ok(quote! {
+            const _: () = {
+                extern crate alloc as __alloc;
+
+                #content
+
+                impl #impl_generics #serde::de::Deserialize<'__de> for #ident #ty_generics
+                #where_clause
+                {
+                    fn deserialize<__D>(
+                        __deserializer: __D,
+                    ) -> ::core::result::Result<Self, __D::Error>
+                    where
+                        __D: #serde::de::Deserializer<'__de>,
+                    {
+                        let __content: __Content<'__de> =
+                            #serde::de::Deserialize::deserialize(__deserializer)?;
+                        let mut __errors = __alloc::string::String::new();
+                        #(#variants)*
+                        ::core::result::Result::Err(<__D::Error as #serde::de::Error>::custom(
+                            format_args!(
+                                "data did not match any variant of enum {}:{}",
+                                #name, __errors,
+                            ),
+                        ))
+                    }
+                }
+            };
+        })
+    }
+
+    /// Generates a self-describing buffer of a deserialized value, and a
+    /// `Deserializer` that borrows it.
+    ///
+    /// This is similar to serde's private `Content` type, which is not a public
+    /// API (and cannot buffer `i128` and `u128`). To keep the generated code
+    /// small, integers are widened to 64 or 128 bits, `f32` to `f64`, and `char`
+    /// to a string; the primitive `Deserialize` impls accept these and check
+    /// the range. Sequences, maps, and enums are replayed by the deserializers
+    /// in `serde::de::value`.
+    fn content(serde: &Path) -> TokenStream {
+        quote! {
+            enum __Content<'de> {
+                Bool(bool),
+                U64(u64),
+                I64(i64),
+                U128(u128),
+                I128(i128),
+                F64(f64),
+                String(__alloc::string::String),
+                Str(&'de str),
+                ByteBuf(__alloc::vec::Vec<u8>),
+                Bytes(&'de [u8]),
+                None,
+                Some(__alloc::boxed::Box<__Content<'de>>),
+                Unit,
+                Newtype(__alloc::boxed::Box<__Content<'de>>),
+                Seq(__alloc::vec::Vec<__Content<'de>>),
+                Map(__alloc::vec::Vec<(__Content<'de>, __Content<'de>)>),
+            }
+
+            impl<'de> #serde::de::Deserialize<'de> for __Content<'de> {
+                fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                where
+                    D: #serde::de::Deserializer<'de>,
+                {
+                    #serde::de::Deserializer::deserialize_any(
+                        deserializer,
+                        __ContentVisitor(::core::marker::PhantomData),
+                    )
+                }
+            }
+
+            struct __ContentVisitor<'de>(::core::marker::PhantomData<__Content<'de>>);
+
+            impl<'de> #serde::de::Visitor<'de> for __ContentVisitor<'de> {
+                type Value = __Content<'de>;
+
+                fn expecting(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.write_str("any value")
+                }
+                fn visit_bool<E>(self, v: bool) -> ::core::result::Result<Self::Value, E> {
+                    ::core::result::Result::Ok(__Content::Bool(v))
+                }
+                fn visit_u64<E>(self, v: u64) -> ::core::result::Result<Self::Value, E> {
+                    ::core::result::Result::Ok(__Content::U64(v))
+                }
+                fn visit_i64<E>(self, v: i64) -> ::core::result::Result<Self::Value, E> {
+                    ::core::result::Result::Ok(__Content::I64(v))
+                }
+                fn visit_u128<E>(self, v: u128) -> ::core::result::Result<Self::Value, E> {
+                    ::core::result::Result::Ok(__Content::U128(v))
+                }
+                fn visit_i128<E>(self, v: i128) -> ::core::result::Result<Self::Value, E> {
+                    ::core::result::Result::Ok(__Content::I128(v))
+                }
+                fn visit_f64<E>(self, v: f64) -> ::core::result::Result<Self::Value, E> {
+                    ::core::result::Result::Ok(__Content::F64(v))
+                }
+                fn visit_str<E>(self, v: &str) -> ::core::result::Result<Self::Value, E> {
+                    ::core::result::Result::Ok(__Content::String(v.into()))
+                }
+                fn visit_borrowed_str<E>(
+                    self,
+                    v: &'de str,
+                ) -> ::core::result::Result<Self::Value, E> {
+                    ::core::result::Result::Ok(__Content::Str(v))
+                }
+                fn visit_string<E>(
+                    self,
+                    v: __alloc::string::String,
+                ) -> ::core::result::Result<Self::Value, E> {
+                    ::core::result::Result::Ok(__Content::String(v))
+                }
+                fn visit_bytes<E>(self, v: &[u8]) -> ::core::result::Result<Self::Value, E> {
+                    ::core::result::Result::Ok(__Content::ByteBuf(v.into()))
+                }
+                fn visit_borrowed_bytes<E>(
+                    self,
+                    v: &'de [u8],
+                ) -> ::core::result::Result<Self::Value, E> {
+                    ::core::result::Result::Ok(__Content::Bytes(v))
+                }
+                fn visit_byte_buf<E>(
+                    self,
+                    v: __alloc::vec::Vec<u8>,
+                ) -> ::core::result::Result<Self::Value, E> {
+                    ::core::result::Result::Ok(__Content::ByteBuf(v))
+                }
+                fn visit_unit<E>(self) -> ::core::result::Result<Self::Value, E> {
+                    ::core::result::Result::Ok(__Content::Unit)
+                }
+                fn visit_none<E>(self) -> ::core::result::Result<Self::Value, E> {
+                    ::core::result::Result::Ok(__Content::None)
+                }
+                fn visit_some<D>(
+                    self,
+                    deserializer: D,
+                ) -> ::core::result::Result<Self::Value, D::Error>
+                where
+                    D: #serde::de::Deserializer<'de>,
+                {
+                    let v = #serde::de::Deserialize::deserialize(deserializer)?;
+                    ::core::result::Result::Ok(__Content::Some(__alloc::boxed::Box::new(v)))
+                }
+                fn visit_newtype_struct<D>(
+                    self,
+                    deserializer: D,
+                ) -> ::core::result::Result<Self::Value, D::Error>
+                where
+                    D: #serde::de::Deserializer<'de>,
+                {
+                    let v = #serde::de::Deserialize::deserialize(deserializer)?;
+                    ::core::result::Result::Ok(__Content::Newtype(__alloc::boxed::Box::new(v)))
+                }
+                fn visit_seq<A>(self, mut seq: A) -> ::core::result::Result<Self::Value, A::Error>
+                where
+                    A: #serde::de::SeqAccess<'de>,
+                {
+                    let mut v = __alloc::vec::Vec::new();
+                    while let ::core::option::Option::Some(e) = seq.next_element()? {
+                        v.push(e);
+                    }
+                    ::core::result::Result::Ok(__Content::Seq(v))
+                }
+                fn visit_map<A>(self, mut map: A) -> ::core::result::Result<Self::Value, A::Error>
+                where
+                    A: #serde::de::MapAccess<'de>,
+                {
+                    let mut v = __alloc::vec::Vec::new();
+                    while let ::core::option::Option::Some(e) = map.next_entry()? {
+                        v.push(e);
+                    }
+                    ::core::result::Result::Ok(__Content::Map(v))
+                }
+            }
+
+            struct __ContentRefDeserializer<'a, 'de: 'a, E> {
+                content: &'a __Content<'de>,
+                err: ::core::marker::PhantomData<E>,
+            }
+
+            impl<'a, 'de, E> __ContentRefDeserializer<'a, 'de, E> {
+                fn new(content: &'a __Content<'de>) -> Self {
+                    Self { content, err: ::core::marker::PhantomData }
+                }
+            }
+
+            impl<'a, 'de, E> #serde::de::IntoDeserializer<'de, E> for &'a __Content<'de>
+            where
+                E: #serde::de::Error,
+            {
+                type Deserializer = __ContentRefDeserializer<'a, 'de, E>;
+
+                fn into_deserializer(self) -> Self::Deserializer {
+                    __ContentRefDeserializer::new(self)
+                }
+            }
+
+            impl<'a, 'de, E> #serde::de::Deserializer<'de> for __ContentRefDeserializer<'a, 'de, E>
+            where
+                E: #serde::de::Error,
+            {
+                type Error = E;
+
+                fn deserialize_any<V>(self, visitor: V) -> ::core::result::Result<V::Value, E>
+                where
+                    V: #serde::de::Visitor<'de>,
+                {
+                    match *self.content {
+                        __Content::Bool(v) => visitor.visit_bool(v),
+                        __Content::U64(v) => visitor.visit_u64(v),
+                        __Content::I64(v) => visitor.visit_i64(v),
+                        __Content::U128(v) => visitor.visit_u128(v),
+                        __Content::I128(v) => visitor.visit_i128(v),
+                        __Content::F64(v) => visitor.visit_f64(v),
+                        __Content::String(ref v) => visitor.visit_str(v),
+                        __Content::Str(v) => visitor.visit_borrowed_str(v),
+                        __Content::ByteBuf(ref v) => visitor.visit_bytes(v),
+                        __Content::Bytes(v) => visitor.visit_borrowed_bytes(v),
+                        __Content::None => visitor.visit_none(),
+                        __Content::Some(ref v) => visitor.visit_some(Self::new(v)),
+                        __Content::Unit => visitor.visit_unit(),
+                        __Content::Newtype(ref v) => visitor.visit_newtype_struct(Self::new(v)),
+                        __Content::Seq(ref v) => {
+                            let mut seq = #serde::de::value::SeqDeserializer::new(v.iter());
+                            let value = visitor.visit_seq(&mut seq)?;
+                            seq.end()?;
+                            ::core::result::Result::Ok(value)
+                        }
+                        __Content::Map(ref v) => {
+                            let mut map = #serde::de::value::MapDeserializer::new(
+                                v.iter().map(|e| (&e.0, &e.1)),
+                            );
+                            let value = visitor.visit_map(&mut map)?;
+                            map.end()?;
+                            ::core::result::Result::Ok(value)
+                        }
+                    }
+                }
+
+                fn deserialize_option<V>(self, visitor: V) -> ::core::result::Result<V::Value, E>
+                where
+                    V: #serde::de::Visitor<'de>,
+                {
+                    match *self.content {
+                        __Content::None | __Content::Unit => visitor.visit_none(),
+                        __Content::Some(ref v) => visitor.visit_some(Self::new(v)),
+                        _ => visitor.visit_some(self),
+                    }
+                }
+
+                fn deserialize_newtype_struct<V>(
+                    self,
+                    _name: &'static str,
+                    visitor: V,
+                ) -> ::core::result::Result<V::Value, E>
+                where
+                    V: #serde::de::Visitor<'de>,
+                {
+                    match *self.content {
+                        __Content::Newtype(ref v) => visitor.visit_newtype_struct(Self::new(v)),
+                        _ => visitor.visit_newtype_struct(self),
+                    }
+                }
+
+                fn deserialize_enum<V>(
+                    self,
+                    _name: &'static str,
+                    _variants: &'static [&'static str],
+                    visitor: V,
+                ) -> ::core::result::Result<V::Value, E>
+                where
+                    V: #serde::de::Visitor<'de>,
+                {
+                    match *self.content {
+                        __Content::Map(ref v) if v.len() == 1 => visitor.visit_enum(
+                            #serde::de::value::MapAccessDeserializer::new(
+                                #serde::de::value::MapDeserializer::<_, E>::new(
+                                    v.iter().map(|e| (&e.0, &e.1)),
+                                ),
+                            ),
+                        ),
+                        __Content::String(ref v) => visitor.visit_enum(
+                            #serde::de::value::StrDeserializer::<E>::new(v),
+                        ),
+                        __Content::Str(v) => visitor.visit_enum(
+                            #serde::de::value::BorrowedStrDeserializer::<E>::new(v),
+                        ),
+                        // Let the visitor report the invalid type.
+                        _ => #serde::de::Deserializer::deserialize_any(self, visitor),
+                    }
+                }
+
+                #serde::forward_to_deserialize_any! {
+                    bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+                    bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+                    identifier ignored_any
+                }
+            }
+        }
+    }
+}
@@ -4,7 +4,8 @@ pub(crate) mod async_buf_read {
     pub(crate) const NAME: &[&str] = &["tokio02::AsyncBufRead"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::tokio::io::AsyncBufRead), None, parse_quote! {
+        let tokio = data.crate_root("tokio");
+        Ok(derive_trait(data, parse_quote!(#tokio::io::AsyncBufRead), None, parse_quote! {
             trait AsyncBufRead {
                 fn poll_fill_buf<'__a>(
                     self: ::core::pin::Pin<&'__a mut Self>,
@@ -22,7 +23,8 @@ pub(crate) mod async_read {
     pub(crate) const NAME: &[&str] = &["tokio02::AsyncRead"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::tokio::io::AsyncRead), None, parse_quote! {
+        let tokio = data.crate_root("tokio");
+        Ok(derive_trait(data, parse_quote!(#tokio::io::AsyncRead), None, parse_quote! {
             trait AsyncRead {
                 unsafe fn prepare_uninitialized_buffer(
                     &self,
@@ -52,7 +54,8 @@ pub(crate) mod async_seek {
     pub(crate) const NAME: &[&str] = &["tokio02::AsyncSeek"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::tokio::io::AsyncSeek), None, parse_quote! {
+        let tokio = data.crate_root("tokio");
+        Ok(derive_trait(data, parse_quote!(#tokio::io::AsyncSeek), None, parse_quote! {
             trait AsyncSeek {
                 fn start_seek(
                     self: ::core::pin::Pin<&mut Self>,
@@ -74,7 +77,8 @@ pub(crate) mod async_write {
     pub(crate) const NAME: &[&str] = &["tokio02::AsyncWrite"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::tokio::io::AsyncWrite), None, parse_quote! {
+        let tokio = data.crate_root("tokio");
+        Ok(derive_trait(data, parse_quote!(#tokio::io::AsyncWrite), None, parse_quote! {
             trait AsyncWrite {
                 fn poll_write(
                     self: ::core::pin::Pin<&mut Self>,
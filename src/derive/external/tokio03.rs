@@ -4,7 +4,8 @@ pub(crate) mod async_buf_read {
     pub(crate) const NAME: &[&str] = &["tokio03::AsyncBufRead"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::tokio::io::AsyncBufRead), None, parse_quote! {
+        let tokio = data.crate_root("tokio");
+        Ok(derive_trait(data, parse_quote!(#tokio::io::AsyncBufRead), None, parse_quote! {
             trait AsyncBufRead {
                 fn poll_fill_buf<'__a>(
                     self: ::core::pin::Pin<&'__a mut Self>,
@@ -22,12 +23,13 @@ pub(crate) mod async_read {
     pub(crate) const NAME: &[&str] = &["tokio03::AsyncRead"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::tokio::io::AsyncRead), None, parse_quote! {
+        let tokio = data.crate_root("tokio");
+        Ok(derive_trait(data, parse_quote!(#tokio::io::AsyncRead), None, parse_quote! {
             trait AsyncRead {
                 fn poll_read(
                     self: ::core::pin::Pin<&mut Self>,
                     cx: &mut ::core::task::Context<'_>,
-                    buf: &mut ::tokio::io::ReadBuf<'_>,
+                    buf: &mut #tokio::io::ReadBuf<'_>,
                 ) -> ::core::task::Poll<::std::io::Result<()>>;
             }
         }))
@@ -40,7 +42,8 @@ pub(crate) mod async_seek {
     pub(crate) const NAME: &[&str] = &["tokio03::AsyncSeek"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::tokio::io::AsyncSeek), None, parse_quote! {
+        let tokio = data.crate_root("tokio");
+        Ok(derive_trait(data, parse_quote!(#tokio::io::AsyncSeek), None, parse_quote! {
             trait AsyncSeek {
                 fn start_seek(
                     self: ::core::pin::Pin<&mut Self>,
@@ -61,7 +64,8 @@ pub(crate) mod async_write {
     pub(crate) const NAME: &[&str] = &["tokio03::AsyncWrite"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
-        Ok(derive_trait(data, parse_quote!(::tokio::io::AsyncWrite), None, parse_quote! {
+        let tokio = data.crate_root("tokio");
+        Ok(derive_trait(data, parse_quote!(#tokio::io::AsyncWrite), None, parse_quote! {
             trait AsyncWrite {
                 fn poll_write(
                     self: ::core::pin::Pin<&mut Self>,
@@ -0,0 +1,74 @@
+pub(crate) mod service {
+    use derive_utils::EnumImpl;
+
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["tower::Service"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        let tower = data.crate_root("tower");
+        let ident = &data.ident;
+        let fst = data.field_types().next();
+        let mut impl_ = EnumImpl::new(data);
+
+        // `Service::Future` differs per variant, so this generates another
+        // enum that implements `Future` and uses it as the associated type.
+        let future = AssocEnum::new(
+            data,
+            "Future",
+            data.field_types().map(|f| parse_quote!(<#f as #tower::Service<__Request>>::Future)),
+            crate::derive::core::future::derive,
+        )?;
+
+        impl_.push_generic_param(parse_quote!(__Request));
+        impl_.set_trait(parse_quote!(#tower::Service<__Request>));
+        impl_.push_where_predicate(parse_quote!(#fst: #tower::Service<__Request>));
+        data.field_types().skip(1).for_each(|f| {
+            impl_.push_where_predicate(parse_quote! {
+                #f: #tower::Service<
+                    __Request,
+                    Response = <#fst as #tower::Service<__Request>>::Response,
+                    Error = <#fst as #tower::Service<__Request>>::Error,
+                >
+            })
+        });
+
+        let future_ident = &future.ident;
+        let future_ty = &future.ty;
+        let poll_ready = data
+            .variant_idents()
+            .map(|v| quote!(#ident::#v(x) => #tower::Service::poll_ready(x, cx)));
+        let call = data
+            .variant_idents()
+            .map(|v| quote!(#ident::#v(x) => #future_ident::#v(#tower::Service::call(x, req))));
+
+        impl_.push_item(parse_quote! {
+            type Response = <#fst as #tower::Service<__Request>>::Response;
+        });
+        impl_.push_item(parse_quote! {
+            type Error = <#fst as #tower::Service<__Request>>::Error;
+        });
+        impl_.push_item(parse_quote! {
+            type Future = #future_ty;
+        });
+        impl_.push_item(parse_quote! {
+            #[inline]
+            fn poll_ready(
+                &mut self,
+                cx: &mut ::core::task::Context<'_>,
+            ) -> ::core::task::Poll<::core::result::Result<(), Self::Error>> {
+                match self { #(#poll_ready,)* }
+            }
+        });
+        impl_.push_item(parse_quote! {
+            #[inline]
+            fn call(&mut self, req: __Request) -> Self::Future {
+                match self { #(#call,)* }
+            }
+        });
+
+        let mut tokens = impl_.build();
+        tokens.extend(future.items);
+        Ok(tokens)
+    }
+}
@@ -0,0 +1,96 @@
+pub(crate) mod subscriber {
+    use derive_utils::EnumImpl;
+
+    use crate::derive::*;
+
+    // `tracing::Subscriber` is the same trait, but the generated code still
+    // refers to `tracing_core` because `tracing` does not re-export
+    // `span::Current`, which `Subscriber::current_span` returns.
+    pub(crate) const NAME: &[&str] = &["tracing_core::Subscriber", "tracing::Subscriber"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        let tracing_core = data.crate_root("tracing_core");
+        let ident = &data.ident;
+        let mut impl_ = EnumImpl::from_trait(
+            data,
+            parse_quote!(#tracing_core::Subscriber),
+            None,
+            parse_quote! {
+                trait Subscriber {
+                    #[inline]
+                    fn on_register_dispatch(&self, subscriber: &#tracing_core::Dispatch);
+                    #[inline]
+                    fn register_callsite(
+                        &self,
+                        metadata: &'static #tracing_core::Metadata<'static>,
+                    ) -> #tracing_core::subscriber::Interest;
+                    #[inline]
+                    fn enabled(&self, metadata: &#tracing_core::Metadata<'_>) -> bool;
+                    #[inline]
+                    fn max_level_hint(
+                        &self,
+                    ) -> ::core::option::Option<#tracing_core::LevelFilter>;
+                    #[inline]
+                    fn new_span(
+                        &self,
+                        span: &#tracing_core::span::Attributes<'_>,
+                    ) -> #tracing_core::span::Id;
+                    #[inline]
+                    fn record(
+                        &self,
+                        span: &#tracing_core::span::Id,
+                        values: &#tracing_core::span::Record<'_>,
+                    );
+                    #[inline]
+                    fn record_follows_from(
+                        &self,
+                        span: &#tracing_core::span::Id,
+                        follows: &#tracing_core::span::Id,
+                    );
+                    #[inline]
+                    fn event_enabled(&self, event: &#tracing_core::Event<'_>) -> bool;
+                    #[inline]
+                    fn event(&self, event: &#tracing_core::Event<'_>);
+                    #[inline]
+                    fn enter(&self, span: &#tracing_core::span::Id);
+                    #[inline]
+                    fn exit(&self, span: &#tracing_core::span::Id);
+                    #[inline]
+                    fn clone_span(&self, id: &#tracing_core::span::Id) -> #tracing_core::span::Id;
+                    #[inline]
+                    #[allow(deprecated)]
+                    fn drop_span(&self, id: #tracing_core::span::Id);
+                    #[inline]
+                    fn try_close(&self, id: #tracing_core::span::Id) -> bool;
+                    #[inline]
+                    fn current_span(&self) -> #tracing_core::span::Current;
+                }
+            },
+        );
+
+        let downcast_raw = data
+            .variant_idents()
+            .map(|v| quote!(#ident::#v(x) => #tracing_core::Subscriber::downcast_raw(x, id)));
+        impl_.push_item(parse_quote! {
+            #[inline]
+            #[allow(unused_unsafe)]
+            unsafe fn downcast_raw(
+                &self,
+                id: ::core::any::TypeId,
+            ) -> ::core::option::Option<*const ()> {
+                if id == ::core::any::TypeId::of::<Self>() {
+                    return ::core::option::Option::Some(self as *const Self as *const ());
+                }
+                // SAFETY: the caller must uphold the safety contract for `downcast_raw`,
+                // and the returned pointer comes from the inner subscriber.
+                unsafe {
+                    match self { #(#downcast_raw,)* }
+                }
+            }
+        });
+
+        let mut impl_ = impl_.build_impl();
+        impl_.attrs.push(parse_quote!(#[allow(unsafe_code)]));
+        Ok(impl_.into_token_stream())
+    }
+}
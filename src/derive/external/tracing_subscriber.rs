@@ -0,0 +1,127 @@
+pub(crate) mod layer {
+    use derive_utils::EnumImpl;
+
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["tracing_subscriber::Layer"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        let tracing_subscriber = data.crate_root("tracing_subscriber");
+        let tracing_core = data.crate_root("tracing_core");
+        let ident = &data.ident;
+        let mut impl_ = EnumImpl::from_trait(
+            data,
+            parse_quote!(#tracing_subscriber::Layer),
+            None,
+            parse_quote! {
+                trait Layer<__S>
+                where
+                    __S: #tracing_core::Subscriber,
+                {
+                    #[inline]
+                    fn on_register_dispatch(&self, subscriber: &#tracing_core::Dispatch);
+                    #[inline]
+                    fn on_layer(&mut self, subscriber: &mut __S);
+                    #[inline]
+                    fn register_callsite(
+                        &self,
+                        metadata: &'static #tracing_core::Metadata<'static>,
+                    ) -> #tracing_core::subscriber::Interest;
+                    #[inline]
+                    fn enabled(
+                        &self,
+                        metadata: &#tracing_core::Metadata<'_>,
+                        ctx: #tracing_subscriber::layer::Context<'_, __S>,
+                    ) -> bool;
+                    #[inline]
+                    fn on_new_span(
+                        &self,
+                        attrs: &#tracing_core::span::Attributes<'_>,
+                        id: &#tracing_core::span::Id,
+                        ctx: #tracing_subscriber::layer::Context<'_, __S>,
+                    );
+                    #[inline]
+                    fn max_level_hint(
+                        &self,
+                    ) -> ::core::option::Option<#tracing_core::LevelFilter>;
+                    #[inline]
+                    fn on_record(
+                        &self,
+                        span: &#tracing_core::span::Id,
+                        values: &#tracing_core::span::Record<'_>,
+                        ctx: #tracing_subscriber::layer::Context<'_, __S>,
+                    );
+                    #[inline]
+                    fn on_follows_from(
+                        &self,
+                        span: &#tracing_core::span::Id,
+                        follows: &#tracing_core::span::Id,
+                        ctx: #tracing_subscriber::layer::Context<'_, __S>,
+                    );
+                    #[inline]
+                    fn event_enabled(
+                        &self,
+                        event: &#tracing_core::Event<'_>,
+                        ctx: #tracing_subscriber::layer::Context<'_, __S>,
+                    ) -> bool;
+                    #[inline]
+                    fn on_event(
+                        &self,
+                        event: &#tracing_core::Event<'_>,
+                        ctx: #tracing_subscriber::layer::Context<'_, __S>,
+                    );
+                    #[inline]
+                    fn on_enter(
+                        &self,
+                        id: &#tracing_core::span::Id,
+                        ctx: #tracing_subscriber::layer::Context<'_, __S>,
+                    );
+                    #[inline]
+                    fn on_exit(
+                        &self,
+                        id: &#tracing_core::span::Id,
+                        ctx: #tracing_subscriber::layer::Context<'_, __S>,
+                    );
+                    #[inline]
+                    fn on_close(
+                        &self,
+                        id: #tracing_core::span::Id,
+                        ctx: #tracing_subscriber::layer::Context<'_, __S>,
+                    );
+                    #[inline]
+                    fn on_id_change(
+                        &self,
+                        old: &#tracing_core::span::Id,
+                        new: &#tracing_core::span::Id,
+                        ctx: #tracing_subscriber::layer::Context<'_, __S>,
+                    );
+                }
+            },
+        );
+
+        let downcast_raw = data.variant_idents().map(
+            |v| quote!(#ident::#v(x) => #tracing_subscriber::Layer::<__S>::downcast_raw(x, id)),
+        );
+        impl_.push_item(parse_quote! {
+            #[inline]
+            #[allow(unused_unsafe)]
+            unsafe fn downcast_raw(
+                &self,
+                id: ::core::any::TypeId,
+            ) -> ::core::option::Option<*const ()> {
+                if id == ::core::any::TypeId::of::<Self>() {
+                    return ::core::option::Option::Some(self as *const Self as *const ());
+                }
+                // SAFETY: the caller must uphold the safety contract for `downcast_raw`,
+                // and the returned pointer comes from the inner layer.
+                unsafe {
+                    match self { #(#downcast_raw,)* }
+                }
+            }
+        });
+
+        let mut impl_ = impl_.build_impl();
+        impl_.attrs.push(parse_quote!(#[allow(unsafe_code)]));
+        Ok(impl_.into_token_stream())
+    }
+}
@@ -7,7 +7,112 @@ pub(crate) mod external;
 pub(crate) mod std;
 pub(crate) mod ty_impls;
 
-use derive_utils::{derive_trait, EnumData as Data};
-use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
-use syn::{parse_quote, Result};
+use ::std::ops::Deref;
+
+use derive_utils::{derive_trait, EnumData};
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::{parse_quote, Ident, Path, Result};
+
+/// The enum to derive traits for, and the root paths of external crates
+/// specified by `crate(<name> = <path>)`.
+pub(crate) struct Data {
+    data: EnumData,
+    crates: Vec<(Ident, Path)>,
+}
+
+impl Data {
+    pub(crate) fn new(data: EnumData, crates: Vec<(Ident, Path)>) -> Self {
+        Self { data, crates }
+    }
+
+    /// Returns the root path of the external crate `name` used by the generated
+    /// code, i.e., the path specified by `crate(<name> = <path>)` or `::<name>`.
+    // Unused if no crate features for external libraries are enabled.
+    #[allow(dead_code)]
+    pub(crate) fn crate_root(&self, name: &str) -> Path {
+        match self.crates.iter().find(|(n, _)| n == name) {
+            Some((_, path)) => path.clone(),
+            None => {
+                let name = Ident::new(name, Span::call_site());
+                parse_quote!(::#name)
+            }
+        }
+    }
+
+    pub(crate) fn into_inner(self) -> EnumData {
+        self.data
+    }
+}
+
+impl Deref for Data {
+    type Target = EnumData;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl ToTokens for Data {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.data.to_tokens(tokens);
+    }
+}
+
+/// An enum generated for an associated type whose value differs per variant,
+/// such as `IntoFuture::IntoFuture`.
+pub(crate) struct AssocEnum {
+    /// The name of the generated enum. Its variants have the same names as
+    /// the variants of the original enum.
+    pub(crate) ident: Ident,
+    /// The generated enum, instantiated with the types passed to `AssocEnum::new`.
+    pub(crate) ty: syn::Type,
+    /// The definition of the generated enum and its trait implementations.
+    pub(crate) items: TokenStream,
+}
+
+impl AssocEnum {
+    /// Generates `enum __<Enum><suffix><__T0, __T1, ..> { <Variant0>(__T0), .. }`
+    /// with the same visibility as `data`, and implements traits to it with `derive`.
+    ///
+    /// `ty_args` is the associated types of each variant, in declaration order.
+    pub(crate) fn new(
+        data: &Data,
+        suffix: &str,
+        ty_args: impl IntoIterator<Item = syn::Type>,
+        derive: impl FnOnce(&Data) -> Result<TokenStream>,
+    ) -> Result<Self> {
+        let vis = &data.vis;
+        let ident = format_ident!("__{}{}", data.ident, suffix);
+        let variants = data.variant_idents();
+        let ty_params: Vec<_> = (0..variants.len()).map(|i| format_ident!("__T{}", i)).collect();
+
+        let assoc = Data::new(
+            syn::parse2(quote! {
+                #[doc(hidden)]
+                #vis enum #ident<#(#ty_params),*> {
+                    #(#variants(#ty_params),)*
+                }
+            })?,
+            data.crates.clone(),
+        );
+
+        let ty_args = ty_args.into_iter();
+        let ty = parse_quote!(#ident<#(#ty_args),*>);
+        let mut items = assoc.to_token_stream();
+        items.extend(derive(&assoc)?);
+        Ok(Self { ident, ty, items })
+    }
+}
+
+/// Returns the type argument of traits that require the type to be specified,
+/// such as `Borrow<str>`.
+#[cfg(feature = "convert")]
+pub(crate) fn type_argument(args: &syn::AngleBracketedGenericArguments) -> Result<&syn::Type> {
+    if args.args.len() == 1 {
+        if let syn::GenericArgument::Type(ty) = &args.args[0] {
+            return Ok(ty);
+        }
+    }
+    Err(error!(args, "expected one type argument"))
+}
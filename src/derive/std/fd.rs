@@ -0,0 +1,54 @@
+// The generated impls are `#[cfg(unix)]` so that the same enum definition can
+// be used on other platforms.
+
+#[cfg(stable_1_63)]
+pub(crate) mod as_fd {
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["AsFd"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        let impl_ =
+            derive_trait(data, parse_quote!(::std::os::unix::io::AsFd), None, parse_quote! {
+                trait AsFd {
+                    #[inline]
+                    fn as_fd(&self) -> ::std::os::unix::io::BorrowedFd<'_>;
+                }
+            });
+        Ok(quote!(#[cfg(unix)] #impl_))
+    }
+}
+
+pub(crate) mod as_raw_fd {
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["AsRawFd"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        let impl_ =
+            derive_trait(data, parse_quote!(::std::os::unix::io::AsRawFd), None, parse_quote! {
+                trait AsRawFd {
+                    #[inline]
+                    fn as_raw_fd(&self) -> ::std::os::unix::io::RawFd;
+                }
+            });
+        Ok(quote!(#[cfg(unix)] #impl_))
+    }
+}
+
+pub(crate) mod into_raw_fd {
+    use crate::derive::*;
+
+    pub(crate) const NAME: &[&str] = &["IntoRawFd"];
+
+    pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        let impl_ =
+            derive_trait(data, parse_quote!(::std::os::unix::io::IntoRawFd), None, parse_quote! {
+                trait IntoRawFd {
+                    #[inline]
+                    fn into_raw_fd(self) -> ::std::os::unix::io::RawFd;
+                }
+            });
+        Ok(quote!(#[cfg(unix)] #impl_))
+    }
+}
@@ -14,8 +14,8 @@ pub(crate) mod read {
                 bufs: &mut [::std::io::IoSliceMut<'_>],
             ) -> ::std::io::Result<usize>;
         };
-
-        // TODO: When `read_initializer` or alternative stabilized, add that conditionally.
+        // TODO: When `can_vector` stabilized, add `is_read_vectored` conditionally.
+        // TODO: When `read_buf` stabilized, add that conditionally.
 
         Ok(derive_trait(data, parse_quote!(::std::io::Read), None, parse_quote! {
             trait Read {
@@ -45,6 +45,15 @@ pub(crate) mod buf_read {
     pub(crate) const NAME: &[&str] = &["BufRead", "io::BufRead"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        #[cfg(not(stable_1_83))]
+        let skip_until = quote!();
+        #[cfg(stable_1_83)]
+        let skip_until = quote! {
+            #[inline]
+            fn skip_until(&mut self, byte: u8) -> ::std::io::Result<usize>;
+        };
+        // TODO: When `buf_read_has_data_left` stabilized, add `has_data_left` conditionally.
+
         Ok(derive_trait(data, parse_quote!(::std::io::BufRead), None, parse_quote! {
             trait BufRead {
                 #[inline]
@@ -61,6 +70,7 @@ pub(crate) mod buf_read {
                     &mut self,
                     buf: &mut ::std::string::String,
                 ) -> ::std::io::Result<usize>;
+                #skip_until
             }
         }))
     }
@@ -72,10 +82,36 @@ pub(crate) mod seek {
     pub(crate) const NAME: &[&str] = &["Seek", "io::Seek"];
 
     pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+        #[cfg(not(stable_1_51))]
+        let stream_position = quote!();
+        #[cfg(stable_1_51)]
+        let stream_position = quote! {
+            #[inline]
+            fn stream_position(&mut self) -> ::std::io::Result<u64>;
+        };
+        #[cfg(not(stable_1_55))]
+        let rewind = quote!();
+        #[cfg(stable_1_55)]
+        let rewind = quote! {
+            #[inline]
+            fn rewind(&mut self) -> ::std::io::Result<()>;
+        };
+        #[cfg(not(stable_1_80))]
+        let seek_relative = quote!();
+        #[cfg(stable_1_80)]
+        let seek_relative = quote! {
+            #[inline]
+            fn seek_relative(&mut self, offset: i64) -> ::std::io::Result<()>;
+        };
+        // TODO: When `seek_stream_len` stabilized, add `stream_len` conditionally.
+
         Ok(derive_trait(data, parse_quote!(::std::io::Seek), None, parse_quote! {
             trait Seek {
                 #[inline]
                 fn seek(&mut self, pos: ::std::io::SeekFrom) -> ::std::io::Result<u64>;
+                #rewind
+                #stream_position
+                #seek_relative
             }
         }))
     }
@@ -97,6 +133,8 @@ pub(crate) mod write {
                 bufs: &[::std::io::IoSlice<'_>],
             ) -> ::std::io::Result<usize>;
         };
+        // TODO: When `can_vector` stabilized, add `is_write_vectored` conditionally.
+        // TODO: When `write_all_vectored` stabilized, add that conditionally.
 
         Ok(derive_trait(data, parse_quote!(::std::io::Write), None, parse_quote! {
             trait Write {
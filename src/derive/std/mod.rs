@@ -1,4 +1,4 @@
-/// `std::error`
-pub(crate) mod error;
+/// `std::os::fd`
+pub(crate) mod fd;
 /// `std::io`
 pub(crate) mod io;
@@ -0,0 +1,85 @@
+use syn::{
+    visit_mut::{self, VisitMut},
+    Ident, TypePath,
+};
+
+use super::check_fields;
+use crate::derive::*;
+
+pub(crate) const NAME: &[&str] = &["Inner"];
+
+pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+    check_fields(data)?;
+    Ok(inner(data))
+}
+
+fn inner(data: &Data) -> TokenStream {
+    let vis = &data.vis;
+    let ident = &data.ident;
+    let variants: Vec<_> = data.variant_idents().collect();
+    let ty_generics = variants.iter().map(|_| quote!(__T));
+    let mapped_generics = variants.iter().map(|_| quote!(__U));
+
+    // The type parameters of the enum are all `__T` in this impl, so replace
+    // them in the where clause of the enum. The enum returned by `map_inner`
+    // has the same where clause, with `__U` instead.
+    let params: Vec<_> = data.generics.type_params().map(|p| &p.ident).collect();
+    let mut where_clause = data.generics.where_clause.clone();
+    let mut mapped_predicates = Vec::new();
+    if let Some(where_clause) = &mut where_clause {
+        for predicate in &where_clause.predicates {
+            let mut predicate = predicate.clone();
+            ReplaceParams(&params, "__U").visit_where_predicate_mut(&mut predicate);
+            mapped_predicates.push(predicate);
+        }
+        ReplaceParams(&params, "__T").visit_where_clause_mut(where_clause);
+    }
+
+    quote! {
+        impl<__T> #ident<#(#ty_generics),*> #where_clause {
+            /// Returns the field of the current variant.
+            #[inline]
+            #vis fn into_inner(self) -> __T {
+                match self { #(#ident::#variants(x))|* => x }
+            }
+
+            /// Returns a reference to the field of the current variant.
+            #[inline]
+            #vis fn as_inner(&self) -> &__T {
+                match self { #(#ident::#variants(x))|* => x }
+            }
+
+            /// Returns a mutable reference to the field of the current variant.
+            #[inline]
+            #vis fn as_inner_mut(&mut self) -> &mut __T {
+                match self { #(#ident::#variants(x))|* => x }
+            }
+
+            /// Applies `f` to the field of the current variant, and returns the
+            /// result in the same variant.
+            #[inline]
+            #vis fn map_inner<__U, __F>(self, f: __F) -> #ident<#(#mapped_generics),*>
+            where
+                __F: ::core::ops::FnOnce(__T) -> __U,
+                #(#mapped_predicates,)*
+            {
+                match self { #(#ident::#variants(x) => #ident::#variants(f(x)),)* }
+            }
+        }
+    }
+}
+
+/// Replaces the given type parameters with the given name.
+struct ReplaceParams<'a>(&'a [&'a Ident], &'a str);
+
+impl VisitMut for ReplaceParams<'_> {
+    fn visit_type_path_mut(&mut self, ty: &mut TypePath) {
+        if ty.qself.is_none() && ty.path.leading_colon.is_none() {
+            let first = &mut ty.path.segments[0];
+            if first.arguments.is_empty() && self.0.contains(&&first.ident) {
+                first.ident = Ident::new(self.1, first.ident.span());
+            }
+        }
+        visit_mut::visit_type_path_mut(self, ty);
+    }
+}
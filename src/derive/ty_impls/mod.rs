@@ -1,2 +1,19 @@
+pub(crate) mod inner;
+pub(crate) mod project;
 #[cfg(feature = "transpose_methods")]
 pub(crate) mod transpose;
+
+use crate::derive::*;
+
+/// Checks that the type of each field is the corresponding type parameter.
+fn check_fields(data: &Data) -> Result<()> {
+    let generics = &data.generics;
+    let fields = data.field_types();
+    let comma = if generics.params.empty_or_trailing() { quote!(,) } else { TokenStream::new() };
+
+    if quote!(#generics).to_string() == quote!(<#(#fields),*#comma>).to_string() {
+        Ok(())
+    } else {
+        Err(error!(data, "all fields need to be generics"))
+    }
+}
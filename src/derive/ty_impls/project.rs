@@ -0,0 +1,111 @@
+use derive_utils::EnumImpl;
+use syn::GenericParam;
+
+use crate::derive::*;
+
+pub(crate) const NAME: &[&str] = &["Project"];
+
+// `project` is sound only if the fields are never moved while the enum is
+// pinned, so this also generates the same guards as pin-project:
+//
+// - The enum implements `Unpin` only if all fields implement `Unpin`. This
+//   conflicts with a manual `impl Unpin`, which would allow calling `project`
+//   on an unpinned enum via `Pin::new`.
+// - The enum must not implement `Drop`, which would allow moving fields out
+//   of the pinned enum via `&mut self`.
+pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
+    let vis = &data.vis;
+    let ident = &data.ident;
+    let proj = format_ident!("__{}Projection", ident);
+
+    let mut generics = data.generics.clone();
+    generics.params.insert(0, GenericParam::Lifetime(parse_quote!('__pin)));
+    {
+        let where_clause = generics.make_where_clause();
+        for f in data.field_types() {
+            where_clause.predicates.push(parse_quote!(#f: '__pin));
+        }
+    }
+    let (_, proj_ty_generics, where_clause) = generics.split_for_impl();
+    let params = &generics.params;
+    let variants = data.variant_idents();
+    let fields = data.field_types();
+
+    let mut tokens = quote! {
+        /// A projection of a pinned mutable reference to the enum: each variant
+        /// has a pinned mutable reference to the corresponding field.
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        #vis enum #proj<#params> #where_clause {
+            #(#variants(::core::pin::Pin<&'__pin mut #fields>),)*
+        }
+    };
+
+    let project = data
+        .variant_idents()
+        .map(|v| quote!(#ident::#v(x) => #proj::#v(::core::pin::Pin::new_unchecked(x))));
+    let mut impl_ = EnumImpl::new(data);
+    impl_.push_item(parse_quote! {
+        /// Projects a pinned mutable reference to the enum into a pinned mutable
+        /// reference to the field of the current variant.
+        #[inline]
+        #vis fn project<'__pin>(
+            self: ::core::pin::Pin<&'__pin mut Self>,
+        ) -> #proj #proj_ty_generics {
+            // SAFETY: the fields are pinned whenever the enum is pinned (structural
+            // pinning): the enum is `Unpin` only if all fields are `Unpin` and does
+            // not implement `Drop` (see `pin_guard`), and this never moves a field
+            // out of the pinned enum.
+            unsafe {
+                match ::core::pin::Pin::get_unchecked_mut(self) { #(#project,)* }
+            }
+        }
+    });
+    let mut impl_ = impl_.build_impl();
+    impl_.attrs.push(parse_quote!(#[allow(unsafe_code)]));
+    impl_.to_tokens(&mut tokens);
+    tokens.extend(pin_guard(data));
+    Ok(tokens)
+}
+
+/// Generates the conditional `Unpin` impl and the `Drop` guard that `project`
+/// relies on.
+fn pin_guard(data: &Data) -> TokenStream {
+    let ident = &data.ident;
+    let (impl_generics, ty_generics, where_clause) = data.generics.split_for_impl();
+
+    let mut unpin_generics = data.generics.clone();
+    unpin_generics.params.insert(0, GenericParam::Lifetime(parse_quote!('__pin)));
+    {
+        let where_clause = unpin_generics.make_where_clause();
+        for f in data.field_types() {
+            // The lifetime prevents this from being a trivial bound, which is
+            // an error if a field is a concrete type that does not implement `Unpin`.
+            where_clause.predicates.push(parse_quote!((&'__pin (), #f): ::core::marker::Unpin));
+        }
+    }
+    let (unpin_impl_generics, _, unpin_where_clause) = unpin_generics.split_for_impl();
+
+    // `const _` is available since Rust 1.37.
+    #[cfg(stable_1_37)]
+    let scope = quote!(_);
+    #[cfg(not(stable_1_37))]
+    let scope = format_ident!("__{}PinGuard", ident).into_token_stream();
+
+    quote! {
+        #[allow(non_upper_case_globals)]
+        const #scope: () = {
+            #[allow(single_use_lifetimes)]
+            impl #unpin_impl_generics ::core::marker::Unpin for #ident #ty_generics
+            #unpin_where_clause
+            {
+            }
+
+            #[allow(dead_code)]
+            trait MustNotImplDrop {}
+            #[allow(unknown_lints, drop_bounds)]
+            impl<T: ::core::ops::Drop> MustNotImplDrop for T {}
+            impl #impl_generics MustNotImplDrop for #ident #ty_generics #where_clause {}
+        };
+    }
+}
@@ -1,8 +1,9 @@
 use derive_utils::EnumImpl;
 use proc_macro2::TokenStream;
 use quote::ToTokens;
-use syn::TypeParam;
+use syn::{Ident, TypeParam};
 
+use super::check_fields;
 use crate::derive::*;
 
 pub(crate) const NAME: &[&str] = &["Transpose"];
@@ -15,19 +16,22 @@ pub(crate) fn derive(data: &Data) -> Result<TokenStream> {
     items.extend(transpose_result(data));
     items.extend(transpose_ok(data));
     items.extend(transpose_err(data));
+    #[cfg(stable_1_36)]
+    items.extend(transpose_poll(data));
+    items.extend(transpose_option_result(data));
+    items.extend(transpose_result_option(data));
     Ok(items)
 }
 
-fn check_fields(data: &Data) -> Result<()> {
-    let generics = &data.generics;
-    let fields = data.field_types();
-    let comma = if generics.params.empty_or_trailing() { quote!(,) } else { TokenStream::new() };
-
-    if quote!(#generics).to_string() == quote!(<#(#fields),*#comma>).to_string() {
-        Ok(())
-    } else {
-        Err(error!(data, "all fields need to be generics"))
-    }
+/// Pushes a generic parameter for the error type of each field.
+fn push_err_fields(impl_: &mut EnumImpl<'_>, data: &Data) -> Vec<Ident> {
+    (0..data.field_types().len())
+        .map(|i| {
+            let id = format_ident!("__E{}", i);
+            impl_.push_generic_param(TypeParam::from(id.clone()).into());
+            id
+        })
+        .collect()
 }
 
 fn transpose_option(data: &Data) -> TokenStream {
@@ -56,13 +60,7 @@ fn transpose_result(data: &Data) -> TokenStream {
     let fields = data.field_types();
     let mut impl_ = EnumImpl::new(data);
 
-    let err_fields: Vec<_> = (0..fields.len())
-        .map(|i| {
-            let id = format_ident!("__E{}", i);
-            impl_.push_generic_param(TypeParam::from(id.clone()).into());
-            id
-        })
-        .collect();
+    let err_fields = push_err_fields(&mut impl_, data);
 
     let transpose = data
         .variant_idents()
@@ -130,3 +128,114 @@ fn transpose_err(data: &Data) -> TokenStream {
     impl_.self_ty = parse_quote!(#ident<#(#ty_generics),*>);
     impl_.into_token_stream()
 }
+
+#[cfg(stable_1_36)]
+fn transpose_poll(data: &Data) -> TokenStream {
+    let ident = &data.ident;
+    let mut impl_ = EnumImpl::new(data);
+
+    let transpose = data.variant_idents().map(|v| quote!(#ident::#v(x) => x.map(#ident::#v)));
+
+    let fields = data.field_types();
+    impl_.push_item(parse_quote! {
+        #[inline]
+        fn transpose(self) -> ::core::task::Poll<#ident<#(#fields),*>> {
+            match self { #(#transpose,)* }
+        }
+    });
+
+    let mut impl_ = impl_.build_impl();
+
+    let ty_generics = data.field_types().map(|f| quote!(::core::task::Poll<#f>));
+    impl_.self_ty = parse_quote!(#ident<#(#ty_generics),*>);
+    impl_.into_token_stream()
+}
+
+fn transpose_option_result(data: &Data) -> TokenStream {
+    let ident = &data.ident;
+    let fields = data.field_types();
+    let mut impl_ = EnumImpl::new(data);
+
+    let err_fields = push_err_fields(&mut impl_, data);
+
+    let transpose = data.variant_idents().map(|v| {
+        quote! {
+            #ident::#v(x) => match x {
+                ::core::option::Option::Some(::core::result::Result::Ok(x)) => {
+                    ::core::result::Result::Ok(::core::option::Option::Some(#ident::#v(x)))
+                }
+                ::core::option::Option::Some(::core::result::Result::Err(e)) => {
+                    ::core::result::Result::Err(#ident::#v(e))
+                }
+                ::core::option::Option::None => {
+                    ::core::result::Result::Ok(::core::option::Option::None)
+                }
+            }
+        }
+    });
+
+    impl_.push_item(parse_quote! {
+        #[inline]
+        fn transpose_option_result(
+            self,
+        ) -> ::core::result::Result<
+            ::core::option::Option<#ident<#(#fields),*>>,
+            #ident<#(#err_fields),*>,
+        > {
+            match self { #(#transpose,)* }
+        }
+    });
+
+    let mut impl_ = impl_.build_impl();
+
+    let ty_generics = data
+        .field_types()
+        .zip(err_fields.iter())
+        .map(|(f, ef)| quote!(::core::option::Option<::core::result::Result<#f, #ef>>));
+    impl_.self_ty = parse_quote!(#ident<#(#ty_generics),*>);
+    impl_.into_token_stream()
+}
+
+fn transpose_result_option(data: &Data) -> TokenStream {
+    let ident = &data.ident;
+    let fields = data.field_types();
+    let mut impl_ = EnumImpl::new(data);
+
+    let err_fields = push_err_fields(&mut impl_, data);
+
+    let transpose = data.variant_idents().map(|v| {
+        quote! {
+            #ident::#v(x) => match x {
+                ::core::result::Result::Ok(::core::option::Option::Some(x)) => {
+                    ::core::option::Option::Some(::core::result::Result::Ok(#ident::#v(x)))
+                }
+                ::core::result::Result::Ok(::core::option::Option::None) => {
+                    ::core::option::Option::None
+                }
+                ::core::result::Result::Err(e) => {
+                    ::core::option::Option::Some(::core::result::Result::Err(#ident::#v(e)))
+                }
+            }
+        }
+    });
+
+    impl_.push_item(parse_quote! {
+        #[inline]
+        fn transpose_result_option(
+            self,
+        ) -> ::core::option::Option<
+            ::core::result::Result<#ident<#(#fields),*>, #ident<#(#err_fields),*>>,
+        > {
+            match self { #(#transpose,)* }
+        }
+    });
+
+    let mut impl_ = impl_.build_impl();
+
+    let ty_generics = data
+        .field_types()
+        .zip(err_fields.iter())
+        .map(|(f, ef)| quote!(::core::result::Result<::core::option::Option<#f>, #ef>));
+    impl_.self_ty = parse_quote!(#ident<#(#ty_generics),*>);
+    impl_.into_token_stream()
+}
@@ -1,11 +1,14 @@
-use derive_utils::EnumData as Data;
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use syn::{
+    parenthesized,
     parse::{Parse, ParseStream},
-    parse_quote, Error, ItemEnum, Path, Result, Token,
+    parse_quote, token, AngleBracketedGenericArguments, Error, Ident, ItemEnum, Path,
+    PathArguments, Result, Token,
 };
 
+use crate::derive::{core::iter::Unstable, Data};
+
 pub(crate) fn attribute(args: TokenStream, input: TokenStream) -> TokenStream {
     expand(args, input).unwrap_or_else(Error::into_compile_error)
 }
@@ -30,6 +33,7 @@ fn get_derive(s: &str) -> Option<DeriveFn> {
         core::convert::as_mut,
         #[cfg(feature = "convert")]
         core::convert::as_ref,
+        core::cmp::transparent_partial_eq,
         core::fmt::debug,
         core::fmt::display,
         #[cfg(feature = "fmt")]
@@ -47,9 +51,9 @@ fn get_derive(s: &str) -> Option<DeriveFn> {
         #[cfg(feature = "fmt")]
         core::fmt::lower_exp,
         core::fmt::write,
-        core::iter::iterator,
-        core::iter::double_ended_iterator,
-        core::iter::exact_size_iterator,
+        core::hash::hasher,
+        core::hash::build_hasher,
+        core::hash::transparent_hash,
         core::iter::fused_iterator,
         #[cfg(feature = "trusted_len")]
         core::iter::trusted_len,
@@ -70,9 +74,15 @@ fn get_derive(s: &str) -> Option<DeriveFn> {
         core::ops::fn_mut,
         #[cfg(feature = "fn_traits")]
         core::ops::fn_once,
-        #[cfg(feature = "generator_trait")]
-        core::ops::generator,
+        #[cfg(any(feature = "coroutine_trait", feature = "generator_trait"))]
+        core::ops::coroutine,
         core::future,
+        #[cfg(stable_1_64)]
+        core::future::into_future,
+        #[cfg(feature = "async_iterator")]
+        core::async_iter::async_iterator,
+        #[cfg(any(feature = "std", stable_1_81))]
+        core::error,
         // std
         #[cfg(feature = "std")]
         std::io::read,
@@ -82,9 +92,15 @@ fn get_derive(s: &str) -> Option<DeriveFn> {
         std::io::seek,
         #[cfg(feature = "std")]
         std::io::write,
+        #[cfg(all(feature = "std", stable_1_63))]
+        std::fd::as_fd,
         #[cfg(feature = "std")]
-        std::error,
+        std::fd::as_raw_fd,
+        #[cfg(feature = "std")]
+        std::fd::into_raw_fd,
         // type impls
+        ty_impls::inner,
+        ty_impls::project,
         #[cfg(feature = "transpose_methods")]
         ty_impls::transpose,
         // futures03
@@ -100,6 +116,10 @@ fn get_derive(s: &str) -> Option<DeriveFn> {
         external::futures03::async_seek,
         #[cfg(feature = "futures03")]
         external::futures03::async_buf_read,
+        #[cfg(feature = "futures03")]
+        external::futures03::fused_future,
+        #[cfg(feature = "futures03")]
+        external::futures03::fused_stream,
         // futures01
         #[cfg(feature = "futures01")]
         external::futures01::future,
@@ -107,6 +127,22 @@ fn get_derive(s: &str) -> Option<DeriveFn> {
         external::futures01::stream,
         #[cfg(feature = "futures01")]
         external::futures01::sink,
+        // bytes1
+        #[cfg(feature = "bytes1")]
+        external::bytes1::buf,
+        #[cfg(feature = "bytes1")]
+        external::bytes1::buf_mut,
+        // http_body1
+        #[cfg(feature = "http_body1")]
+        external::http_body1::body,
+        // log
+        #[cfg(feature = "log")]
+        external::log,
+        // rand_core06
+        #[cfg(feature = "rand_core06")]
+        external::rand_core06::rng_core,
+        #[cfg(feature = "rand_core06")]
+        external::rand_core06::crypto_rng,
         // rayon
         #[cfg(feature = "rayon")]
         external::rayon::par_iter,
@@ -117,6 +153,10 @@ fn get_derive(s: &str) -> Option<DeriveFn> {
         // serde
         #[cfg(feature = "serde")]
         external::serde::serialize,
+        #[cfg(feature = "serde")]
+        external::serde::serializer,
+        #[cfg(feature = "serde")]
+        external::serde::deserialize,
         // tokio1
         #[cfg(feature = "tokio1")]
         external::tokio1::async_read,
@@ -149,13 +189,158 @@ fn get_derive(s: &str) -> Option<DeriveFn> {
         external::tokio01::async_read,
         #[cfg(feature = "tokio01")]
         external::tokio01::async_write,
+        // tower
+        #[cfg(feature = "tower")]
+        external::tower::service,
+        // tracing_core
+        #[cfg(feature = "tracing_core")]
+        external::tracing_core::subscriber,
+        // tracing_subscriber
+        #[cfg(feature = "tracing_subscriber")]
+        external::tracing_subscriber::layer,
+    }
+
+    None
+}
+
+type DeriveWithArgsFn = fn(&'_ Data, &'_ AngleBracketedGenericArguments) -> Result<TokenStream>;
+
+/// Returns the derive function for traits with generic arguments, such as `Borrow<str>`.
+#[cfg_attr(not(feature = "convert"), allow(unused_variables))]
+fn get_derive_with_args(
+    path: &Path,
+) -> Option<(DeriveWithArgsFn, &AngleBracketedGenericArguments)> {
+    let last = path.segments.last()?;
+    let args = match &last.arguments {
+        PathArguments::AngleBracketed(args) => args,
+        _ => return None,
+    };
+    let mut path = path.clone();
+    path.segments.last_mut().unwrap().arguments = PathArguments::None;
+    let s = path.to_token_stream().to_string().replace(" ", "");
+
+    macro_rules! match_derive {
+        ($($(#[$meta:meta])* $($arm:ident)::*,)*) => {$(
+            $(#[$meta])*
+            {
+                if crate::derive::$($arm)::*::NAME.iter().any(|name| *name == s) {
+                    return Some((crate::derive::$($arm)::*::derive_with_args as DeriveWithArgsFn, args))
+                }
+            }
+        )*};
+    }
+
+    match_derive! {
+        #[cfg(feature = "convert")]
+        core::borrow::borrow,
+        #[cfg(feature = "convert")]
+        core::borrow::borrow_mut,
+        #[cfg(feature = "convert")]
+        core::convert::as_mut,
+        #[cfg(feature = "convert")]
+        core::convert::as_ref,
+    }
+
+    None
+}
+
+#[cfg(feature = "convert")]
+type DeriveWithBorrowFn = fn(&'_ Data, &'_ syn::Type) -> Result<TokenStream>;
+
+/// Returns the derive function for traits that must be consistent with
+/// `Borrow<T>` if it is also derived, such as `transparent::PartialEq`.
+#[cfg(feature = "convert")]
+fn get_derive_with_borrow(s: &str) -> Option<DeriveWithBorrowFn> {
+    macro_rules! match_derive {
+        ($($(#[$meta:meta])* $($arm:ident)::*,)*) => {$(
+            $(#[$meta])*
+            {
+                if crate::derive::$($arm)::*::NAME.iter().any(|name| *name == s) {
+                    return Some(crate::derive::$($arm)::*::derive_with_borrow as DeriveWithBorrowFn)
+                }
+            }
+        )*};
+    }
+
+    match_derive! {
+        core::cmp::eq,
+        core::cmp::transparent_partial_eq,
+        core::hash::transparent_hash,
     }
 
     None
 }
 
+/// Returns the type argument of `Borrow<T>` in `args`, if any.
+#[cfg(feature = "convert")]
+fn borrowed_type(args: &[(String, Path)]) -> Option<&syn::Type> {
+    args.iter().find_map(|(_, path)| {
+        let last = path.segments.last()?;
+        match &last.arguments {
+            PathArguments::AngleBracketed(args)
+                if path.segments.len() == 1 && last.ident == "Borrow" =>
+            {
+                crate::derive::type_argument(args).ok()
+            }
+            _ => None,
+        }
+    })
+}
+
+type DeriveWithUnstableFn = fn(&'_ Data, &'_ Unstable) -> Result<TokenStream>;
+
+/// Returns the derive function for traits that forward unstable methods only
+/// if requested, such as `Iterator` with `iter::TryFold`.
+fn get_derive_with_unstable(s: &str) -> Option<DeriveWithUnstableFn> {
+    macro_rules! match_derive {
+        ($($(#[$meta:meta])* $($arm:ident)::*,)*) => {$(
+            $(#[$meta])*
+            {
+                if crate::derive::$($arm)::*::NAME.iter().any(|name| *name == s) {
+                    return Some(crate::derive::$($arm)::*::derive as DeriveWithUnstableFn)
+                }
+            }
+        )*};
+    }
+
+    match_derive! {
+        core::iter::iterator,
+        core::iter::double_ended_iterator,
+        core::iter::exact_size_iterator,
+    }
+
+    None
+}
+
+/// Removes the arguments that request unstable methods, such as `iter::TryFold`,
+/// from `args`.
+fn take_unstable(args: &mut Vec<(String, Path)>) -> Result<Unstable> {
+    let mut unstable = Unstable::default();
+    let mut i = 0;
+    while i < args.len() {
+        let derives = match Unstable::derives(&args[i].0) {
+            Some(derives) => derives,
+            None => {
+                i += 1;
+                continue;
+            }
+        };
+        let (s, path) = args.remove(i);
+        if !cfg!(feature = "unstable") {
+            return Err(error!(path, "`{}` requires the `unstable` crate feature", s));
+        }
+        if !args.iter().any(|(x, _)| derives.contains(&&**x)) {
+            return Err(error!(path, "`{}` requires `{}` derive", s, derives.join("` or `")));
+        }
+        unstable.set(&s);
+    }
+    Ok(unstable)
+}
+
 struct Args {
     inner: Vec<(String, Path)>,
+    /// `crate(<name> = <path>, ...)`
+    crates: Vec<(Ident, Path)>,
 }
 
 impl Parse for Args {
@@ -165,9 +350,20 @@ impl Parse for Args {
         }
 
         let mut inner = Vec::new();
+        let mut crates: Option<Vec<(Ident, Path)>> = None;
         while !input.is_empty() {
-            let path = input.parse()?;
-            inner.push((to_trimmed_string(&path), path));
+            if input.peek(Token![crate]) && input.peek2(token::Paren) {
+                let kw: Token![crate] = input.parse()?;
+                if crates.is_some() {
+                    return Err(error!(kw, "duplicate `crate` argument"));
+                }
+                let content;
+                let _ = parenthesized!(content in input);
+                crates = Some(parse_crates(&content)?);
+            } else {
+                let path = input.parse()?;
+                inner.push((to_trimmed_string(&path), path));
+            }
 
             if input.is_empty() {
                 break;
@@ -175,10 +371,33 @@ impl Parse for Args {
             let _: Token![,] = input.parse()?;
         }
 
-        Ok(Self { inner })
+        Ok(Self { inner, crates: crates.unwrap_or_default() })
     }
 }
 
+/// Parses the content of `crate(<name> = <path>, ...)`.
+pub(crate) fn parse_crates(input: ParseStream<'_>) -> Result<Vec<(Ident, Path)>> {
+    let mut crates: Vec<(Ident, Path)> = Vec::new();
+    while !input.is_empty() {
+        let name: Ident = input.parse()?;
+        let _: Token![=] = input.parse()?;
+        let path: Path = input.parse()?;
+        if name == "core" || name == "std" {
+            return Err(error!(name, "the path of the `{}` crate cannot be changed", name));
+        }
+        if crates.iter().any(|(n, _)| *n == name) {
+            return Err(error!(name, "duplicate crate name `{}`", name));
+        }
+        crates.push((name, path));
+
+        if input.is_empty() {
+            break;
+        }
+        let _: Token![,] = input.parse()?;
+    }
+    Ok(crates)
+}
+
 fn get_trait_deps(s: &str) -> Option<&'static [&'static str]> {
     Some(match s {
         "Copy" => &["Clone"],
@@ -197,7 +416,7 @@ fn get_trait_deps(s: &str) -> Option<&'static [&'static str]> {
         "TrustedLen" => &["Iterator"],
         #[cfg(feature = "std")]
         "BufRead" | "io::BufRead" => &["Read"],
-        #[cfg(feature = "std")]
+        #[cfg(any(feature = "std", stable_1_81))]
         "Error" => &["Display", "Debug"],
         #[cfg(feature = "rayon")]
         "rayon::IndexedParallelIterator" => &["rayon::ParallelIterator"],
@@ -224,6 +443,8 @@ fn exists_alias(s: &str, v: &[(&str, Option<&Path>)]) -> bool {
             // core
             core::fmt::debug,
             core::fmt::display,
+            core::hash::hasher,
+            core::hash::build_hasher,
             // std
             #[cfg(feature = "std")]
             std::io::read,
@@ -241,13 +462,40 @@ fn exists_alias(s: &str, v: &[(&str, Option<&Path>)]) -> bool {
     get_alias(s).map_or(false, |x| v.iter().any(|(s, _)| *s == x))
 }
 
+/// Returns `true` if `s` is the `transparent::` version of `dep`, e.g.,
+/// `transparent::PartialEq` satisfies the `PartialEq` dependency of `Eq`.
+fn is_transparent_of(s: &str, dep: &str) -> bool {
+    s.starts_with("transparent::") && s["transparent::".len()..] == *dep
+}
+
 fn expand(args: TokenStream, input: TokenStream) -> Result<TokenStream> {
-    let data = syn::parse2::<Data>(input)?;
-    let args = syn::parse2::<Args>(args)?.inner;
+    let Args { inner: mut args, crates } = syn::parse2::<Args>(args)?;
+    let data = Data::new(syn::parse2(input)?, crates);
+    let unstable = take_unstable(&mut args)?;
+    #[cfg(any(feature = "coroutine_trait", feature = "generator_trait"))]
+    {
+        let names = crate::derive::core::ops::coroutine::NAME;
+        let mut iter = args.iter().filter(|(s, _)| names.contains(&&**s));
+        if let (Some(_), Some((_, path))) = (iter.next(), iter.next()) {
+            return Err(error!(
+                path,
+                "`Coroutine` and `Generator` cannot be derived together because `Generator` is an alias of `Coroutine`"
+            ));
+        }
+    }
+    #[cfg(feature = "convert")]
+    let borrowed = borrowed_type(&args);
+    // `Eq` is derived with the bounds of `PartialEq` only if `transparent::PartialEq`
+    // compares the borrowed values.
+    #[cfg(feature = "convert")]
+    let borrowed_eq = borrowed.filter(|_| args.iter().any(|(s, _)| s == "transparent::PartialEq"));
     let args = args.iter().fold(Vec::new(), |mut v, (s, arg)| {
         if let Some(traits) = get_trait_deps(s) {
-            traits.iter().filter(|&x| !args.iter().any(|(s, _)| s == x)).for_each(|s| {
-                if !exists_alias(s, &v) {
+            let exists = |x: &str| args.iter().any(|(s, _)| s == x || is_transparent_of(s, x));
+            traits.iter().filter(|x| !exists(x)).for_each(|s| {
+                // Multiple traits may depend on the same trait, e.g., `DoubleEndedIterator`
+                // and `ExactSizeIterator`.
+                if !exists_alias(s, &v) && !v.iter().any(|(x, _)| x == s) {
                     v.push((s, None));
                 }
             });
@@ -261,6 +509,28 @@ fn expand(args: TokenStream, input: TokenStream) -> Result<TokenStream> {
     let mut derive = Vec::new();
     let mut items = TokenStream::new();
     for (s, arg) in args {
+        if let Some((f, generic_args)) = arg.and_then(get_derive_with_args) {
+            items.extend(
+                f(&data, generic_args).map_err(|e| error!(data, "`enum_derive({})` {}", s, e))?,
+            );
+            continue;
+        }
+        #[cfg(feature = "convert")]
+        {
+            let borrowed = if s == "Eq" { borrowed_eq } else { borrowed };
+            if let (Some(f), Some(ty)) = (get_derive_with_borrow(s), borrowed) {
+                items.extend(
+                    f(&data, ty).map_err(|e| error!(data, "`enum_derive({})` {}", s, e))?,
+                );
+                continue;
+            }
+        }
+        if let Some(f) = get_derive_with_unstable(s) {
+            items.extend(
+                f(&data, &unstable).map_err(|e| error!(data, "`enum_derive({})` {}", s, e))?,
+            );
+            continue;
+        }
         match (get_derive(s), arg) {
             (Some(f), _) => {
                 items.extend(f(&data).map_err(|e| error!(data, "`enum_derive({})` {}", s, e))?);
@@ -270,7 +540,7 @@ fn expand(args: TokenStream, input: TokenStream) -> Result<TokenStream> {
         }
     }
 
-    let mut item: ItemEnum = data.into();
+    let mut item: ItemEnum = data.into_inner().into();
     if !derive.is_empty() {
         item.attrs.push(parse_quote!(#[derive(#(#derive),*)]));
     }
@@ -510,6 +510,90 @@
 //! }
 //! ```
 //!
+//! ## Auto trait assertions
+//!
+//! The generated enum implements auto traits such as `Send` if all variants
+//! implement them, so these traits don't need to be derived. Instead, if
+//! `Send`, `Sync`, `Unpin`, or `UnwindSafe` (requires `"std"` crate feature or Rust 1.56+)
+//! is specified, `#[auto_enum]` checks that each branch implements them. If a
+//! branch does not, the error points at that branch, not at the place where
+//! the enum is used.
+//!
+//! ```rust
+//! use auto_enums::auto_enum;
+//!
+//! #[auto_enum(Iterator, Send, Sync)]
+//! fn foo(x: i32) -> impl Iterator<Item = i32> + Send + Sync {
+//!     match x {
+//!         0 => 1..10,
+//!         _ => vec![5, 10].into_iter(),
+//!     }
+//! }
+//! ```
+//!
+//! ## Inspecting branches
+//!
+//! If `inspect` is specified, `#[auto_enum]` also generates the following
+//! inherent items on the enum, which are useful for logging which branch
+//! produced a value:
+//!
+//! * `const VARIANT_COUNT: usize` — the number of branches.
+//! * `fn variant_count(&self) -> usize` — returns `VARIANT_COUNT`.
+//! * `fn variant_index(&self) -> usize` — returns the index of the branch that produced the value.
+//! * `fn variant_source(&self) -> &'static str` — returns the source of the branch that produced the value.
+//!
+//! The source of a branch is its tokens (e.g., `1 .. 10` instead of `1..10`).
+//! On nightly compilers, the spans of the branch can be joined, so its source
+//! text is used instead unless it is generated by a macro.
+//!
+//! These can only be called where the concrete type is visible, i.e., not
+//! through the `impl Trait` returned by a function.
+//!
+//! ```rust
+//! use auto_enums::auto_enum;
+//!
+//! #[auto_enum]
+//! fn foo(x: i32) -> i32 {
+//!     #[auto_enum(Iterator, inspect)]
+//!     let iter = match x {
+//!         0 => 1..10,
+//!         _ => vec![5, 10].into_iter(),
+//!     };
+//!     assert_eq!(iter.variant_count(), 2);
+//!     println!("branch {}: {}", iter.variant_index(), iter.variant_source());
+//!     iter.sum()
+//! }
+//! ```
+//!
+//! ## Instrumenting branches
+//!
+//! If `instrument` is specified and the `"tracing"` crate feature is enabled,
+//! `#[auto_enum]` emits a [tracing] event at the `TRACE` level each time a
+//! branch produces a value. The event records the following fields:
+//!
+//! * `function` — the name of the function in which the branch is, if `#[auto_enum]` is used on or inside a function.
+//! * `branch` — the index of the branch.
+//! * `source` — the source of the branch.
+//!
+//! The file and line in the metadata of the event are those of the branch.
+//! The generated code refers to `::tracing`, which
+//! can be changed by `crate(tracing = <path>)` argument. If the `"tracing"`
+//! crate feature is disabled, `instrument` generates nothing.
+//!
+//! ```rust
+//! # #[cfg(feature = "tracing")]
+//! # extern crate tracing_crate as tracing;
+//! use auto_enums::auto_enum;
+//!
+//! #[auto_enum(Iterator, instrument)]
+//! fn foo(x: i32) -> impl Iterator<Item = i32> {
+//!     match x {
+//!         0 => 1..10,
+//!         _ => vec![5, 10].into_iter(),
+//!     }
+//! }
+//! ```
+//!
 //! ## Rust Nightly
 //!
 //! When using `#[auto_enum]` for expressions and statements, `#[auto_enum]` for
@@ -588,6 +672,7 @@
 //! }
 //! ```
 //!
+//! [bytes1]: https://docs.rs/bytes/1
 //! [derive_utils]: https://github.com/taiki-e/derive_utils
 //!
 //! # Supported traits
@@ -608,14 +693,41 @@
 //! - [`Extend`](https://doc.rust-lang.org/std/iter/trait.Extend.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/iter/extend.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/iter/extend.expanded.rs)
 //! - [`TrustedLen`](https://doc.rust-lang.org/std/iter/trait.TrustedLen.html) *(requires `"trusted_len"` and `"unstable"` crate features)* - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/iter/trusted_len.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/iter/trusted_len.expanded.rs)
 //!
+//! Unstable methods of these traits are forwarded only if they are requested by
+//! the following arguments *(requires `"unstable"` crate feature)*, because the
+//! generated code requires `#![feature(...)]` in the crate that uses the derive:
+//!
+//! - `iter::TryFold` - forward `try_fold` and `try_rfold` in `Iterator` and `DoubleEndedIterator`, so that methods such as `any`, `all`, and `find` use the internal iteration of the inner iterators. Requires `#![feature(try_trait_v2)]`.
+//! - `iter::AdvanceBy` - forward `advance_by` and `advance_back_by` in `Iterator` and `DoubleEndedIterator`. Requires `#![feature(iter_advance_by)]`.
+//! - `iter::IsEmpty` - forward `is_empty` in `ExactSizeIterator`. Requires `#![feature(exact_size_is_empty)]`.
+//!
+//! ```rust,ignore
+//! #![feature(try_trait_v2)]
+//!
+//! use auto_enums::enum_derive;
+//!
+//! #[enum_derive(Iterator, DoubleEndedIterator, iter::TryFold)]
+//! enum Enum<A, B> {
+//!     A(A),
+//!     B(B),
+//! }
+//! ```
+//!
 //! *See also [iter-enum] crate.*
 //!
 //! ### `[std|core]::future`
 //!
 //! * [`Future`](https://doc.rust-lang.org/std/future/trait.Future.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/future.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/future.expanded.rs)
+//! * [`IntoFuture`](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) *(requires Rust 1.64+)* - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/into_future.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/into_future.expanded.rs)
+//!
+//!   `IntoFuture::IntoFuture` is another generated enum that implements `Future`.
 //!
 //! *See also [futures-enum] crate.*
 //!
+//! ### `[std|core]::async_iter`
+//!
+//! * [`AsyncIterator`](https://doc.rust-lang.org/nightly/std/async_iter/trait.AsyncIterator.html) *(requires `"async_iterator"` and `"unstable"` crate features)*
+//!
 //! ### `std::io` *(requires `"std"` crate feature)*
 //!
 //! * [`Read`](https://doc.rust-lang.org/std/io/trait.Read.html) (alias: `io::Read`) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/io/read.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/io/read.expanded.rs)
@@ -625,6 +737,14 @@
 //!
 //! *See also [io-enum] crate.*
 //!
+//! ### `std::os::fd` *(requires `"std"` crate feature)*
+//!
+//! The generated implementations are only available on Unix platforms.
+//!
+//! * [`AsFd`](https://doc.rust-lang.org/std/os/fd/trait.AsFd.html) *(requires Rust 1.63+)* - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/fd/as_fd.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/fd/as_fd.expanded.rs)
+//! * [`AsRawFd`](https://doc.rust-lang.org/std/os/fd/trait.AsRawFd.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/fd/as_raw_fd.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/fd/as_raw_fd.expanded.rs)
+//! * [`IntoRawFd`](https://doc.rust-lang.org/std/os/fd/trait.IntoRawFd.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/fd/into_raw_fd.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/fd/into_raw_fd.expanded.rs)
+//!
 //! ### `[std|core]::ops`
 //!
 //! * [`Deref`](https://doc.rust-lang.org/std/ops/trait.Deref.html) *(requires `"ops"` crate feature)*
@@ -635,13 +755,37 @@
 //! * [`Fn`](https://doc.rust-lang.org/std/ops/trait.Fn.html) *(requires `"fn_traits"` and `"unstable"` crate features)*
 //! * [`FnMut`](https://doc.rust-lang.org/std/ops/trait.FnMut.html) *(requires `"fn_traits"` and `"unstable"` crate features)*
 //! * [`FnOnce`](https://doc.rust-lang.org/std/ops/trait.FnOnce.html) *(requires `"fn_traits"` and `"unstable"` crate features)*
-//! * [`Generator`](https://doc.rust-lang.org/nightly/std/ops/trait.Generator.html) *(requires `"generator_trait"` and `"unstable"` crate features)*
+//! * [`Coroutine`](https://doc.rust-lang.org/nightly/std/ops/trait.Coroutine.html) *(requires `"coroutine_trait"` and `"unstable"` crate features)*
+//! * `Generator` *(requires `"generator_trait"` and `"unstable"` crate features)*
+//!
+//!   `Generator` is the name of `Coroutine` before Rust 1.75 nightly. `Generator`
+//!   and `Coroutine` are aliases: both implement the trait that exists on the
+//!   current compiler, so they cannot be derived together.
 //!
 //! ### `[std|core]::convert`
 //!
 //! * [`AsRef`](https://doc.rust-lang.org/std/convert/trait.AsRef.html) *(requires `"convert"` crate feature)*
 //! * [`AsMut`](https://doc.rust-lang.org/std/convert/trait.AsMut.html) *(requires `"convert"` crate feature)*
 //!
+//! These implement `AsRef<T>` and `AsMut<T>` for all `T` that all fields implement. If the type is specified, such as `AsRef<str>`, only `AsRef<str>` is implemented - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/convert/as_ref.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/convert/as_ref.expanded.rs)
+//!
+//! ### `[std|core]::borrow`
+//!
+//! * [`Borrow`](https://doc.rust-lang.org/std/borrow/trait.Borrow.html) *(requires `"convert"` crate feature)*
+//! * [`BorrowMut`](https://doc.rust-lang.org/std/borrow/trait.BorrowMut.html) *(requires `"convert"` crate feature)*
+//!
+//! [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/borrow/borrow.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/borrow/borrow.expanded.rs)
+//!
+//! These require the borrowed type to be specified, such as `Borrow<str>`, because `Borrow<T>` is already implemented for all `T`.
+//!
+//! ### `[std|core]::cmp`
+//!
+//! * `transparent::PartialEq` - implements [`PartialEq`](https://doc.rust-lang.org/std/cmp/trait.PartialEq.html) by comparing the inner values of the same variant - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/cmp/transparent_partial_eq.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/cmp/transparent_partial_eq.expanded.rs)
+//!
+//!   Values in different variants are never equal. Unlike `#[derive(PartialEq)]`, this requires the field types, not the type parameters, to implement `PartialEq`. This satisfies the `PartialEq` requirement of `Eq`, `PartialOrd`, and `Ord`.
+//!
+//!   If `Borrow<T>` is also derived, this compares the values borrowed as `T` instead, so values in different variants are equal if their borrowed values are equal, as `Borrow` requires. In this case, `Eq` is also implemented with the same bounds.
+//!
 //! ### `[std|core]::fmt`
 //!
 //! * [`Debug`](https://doc.rust-lang.org/std/fmt/trait.Debug.html) (alias: `fmt::Debug`) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/debug.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/debug.expanded.rs)
@@ -655,10 +799,41 @@
 //! * [`fmt::UpperHex`](https://doc.rust-lang.org/std/fmt/trait.UpperHex.html) *(requires `"fmt"` crate feature)*
 //! * [`fmt::Write`](https://doc.rust-lang.org/std/fmt/trait.Write.html)
 //!
-//! ### `std::error` *(requires `"std"` crate feature)*
+//! ### `[std|core]::hash`
+//!
+//! * [`Hasher`](https://doc.rust-lang.org/std/hash/trait.Hasher.html) (alias: `hash::Hasher`) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/hash/hasher.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/hash/hasher.expanded.rs)
+//! * [`BuildHasher`](https://doc.rust-lang.org/std/hash/trait.BuildHasher.html) (alias: `hash::BuildHasher`) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/hash/build_hasher.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/hash/build_hasher.expanded.rs)
+//!
+//!   `BuildHasher::Hasher` is another generated enum that implements `Hasher`.
+//!
+//! * `transparent::Hash` - implements [`Hash`](https://doc.rust-lang.org/std/hash/trait.Hash.html) by hashing only the inner value - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/hash/transparent_hash.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/hash/transparent_hash.expanded.rs)
+//!
+//!   Unlike `#[derive(Hash)]`, this does not hash the discriminant, so the hash value is the same as the hash value of the inner value. If `Borrow<T>` is also derived, this hashes the value borrowed as `T`. Combined with `Borrow`, `transparent::PartialEq`, and `Eq`, this allows a generated enum to be used as a map key that can be looked up by the inner type:
+//!
+//!   ```rust
+//!   use std::collections::HashMap;
+//!
+//!   use auto_enums::enum_derive;
+//!
+//!   #[enum_derive(transparent::Hash, transparent::PartialEq, Eq, Borrow<str>)]
+//!   enum Key<A, B> {
+//!       A(A),
+//!       B(B),
+//!   }
+//!
+//!   let mut map: HashMap<Key<String, Box<str>>, i32> = HashMap::new();
+//!   map.insert(Key::A("a".to_string()), 1);
+//!   map.insert(Key::B("b".into()), 2);
+//!   assert_eq!(map["a"], 1);
+//!   assert_eq!(map["b"], 2);
+//!   ```
+//!
+//! ### `[std|core]::error` *(requires `"std"` crate feature or Rust 1.81+)*
 //!
 //! * [`Error`](https://doc.rust-lang.org/std/error/trait.Error.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/error.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/std/error.expanded.rs)
 //!
+//! On Rust 1.81+, this implements `core::error::Error`, so it can also be used in `no_std` crates.
+//!
 //! ## External libraries
 //!
 //! You can use support for external library traits by activating each crate feature.
@@ -679,10 +854,25 @@
 //! }
 //! ```
 //!
+//! The generated code refers to external libraries by absolute paths such as `::futures` and `::tokio`. If these crates are renamed in `Cargo.toml` or re-exported from another crate, you can use `crate(<name> = <path>)` argument to change the root of these paths. Crates that are not used by the generated code are ignored. It is an error to change the path of `core` or `std`. This argument is also available in `#[auto_enum]`.
+//!
+//! ```rust
+//! # extern crate futures03_crate as my_futures;
+//! use auto_enums::enum_derive;
+//!
+//! #[enum_derive(futures03::Stream, crate(futures = ::my_futures))]
+//! enum Foo<A, B> {
+//!     A(A),
+//!     B(B),
+//! }
+//! ```
+//!
 //! ### [futures v0.3][futures03] *(requires `"futures03"` crate feature)*
 //!
 //! * [`futures03::Stream`](https://docs.rs/futures/0.3/futures/stream/trait.Stream.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/futures/stream.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/futures/stream.expanded.rs)
 //! * [`futures03::Sink`](https://docs.rs/futures/0.3/futures/sink/trait.Sink.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/futures/sink.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/futures/sink.expanded.rs)
+//! * [`futures03::FusedFuture`](https://docs.rs/futures/0.3/futures/future/trait.FusedFuture.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/futures/fused_future.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/futures/fused_future.expanded.rs)
+//! * [`futures03::FusedStream`](https://docs.rs/futures/0.3/futures/stream/trait.FusedStream.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/futures/fused_stream.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/futures/fused_stream.expanded.rs)
 //! * [`futures03::AsyncRead`](https://docs.rs/futures/0.3/futures/io/trait.AsyncRead.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/futures/async_read.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/futures/async_read.expanded.rs)
 //! * [`futures03::AsyncWrite`](https://docs.rs/futures/0.3/futures/io/trait.AsyncWrite.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/futures/async_write.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/futures/async_write.expanded.rs)
 //! * [`futures03::AsyncSeek`](https://docs.rs/futures/0.3/futures/io/trait.AsyncSeek.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/futures/async_seek.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/futures/async_seek.expanded.rs)
@@ -696,6 +886,26 @@
 //! * [`futures01::Stream`](https://docs.rs/futures/0.1/futures/stream/trait.Stream.html)
 //! * [`futures01::Sink`](https://docs.rs/futures/0.1/futures/sink/trait.Sink.html)
 //!
+//! ### [bytes v1][bytes1] *(requires `"bytes1"` crate feature)*
+//!
+//! * [`bytes1::Buf`](https://docs.rs/bytes/1/bytes/trait.Buf.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/bytes/buf.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/bytes/buf.expanded.rs)
+//!   * `chunks_vectored` is forwarded only when the `std` crate feature is enabled, because it requires the `std` feature of `bytes`.
+//! * [`bytes1::BufMut`](https://docs.rs/bytes/1/bytes/trait.BufMut.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/bytes/buf_mut.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/bytes/buf_mut.expanded.rs)
+//!
+//! ### [http-body v1][http_body1] *(requires `"http_body1"` crate feature)*
+//!
+//! * [`http_body1::Body`](https://docs.rs/http-body/1/http_body/trait.Body.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/http_body/body.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/http_body/body.expanded.rs)
+//!
+//! ### [log] *(requires `"log"` crate feature)*
+//!
+//! * [`log::Log`](https://docs.rs/log/0.4/log/trait.Log.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/log/log.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/log/log.expanded.rs)
+//!
+//! ### [rand_core v0.6][rand_core06] *(requires `"rand_core06"` crate feature)*
+//!
+//! * [`rand_core06::RngCore`](https://docs.rs/rand_core/0.6/rand_core/trait.RngCore.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/rand_core/rng_core.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/rand_core/rng_core.expanded.rs)
+//! * [`rand_core06::CryptoRng`](https://docs.rs/rand_core/0.6/rand_core/trait.CryptoRng.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/rand_core/crypto_rng.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/rand_core/crypto_rng.expanded.rs)
+//!   * This is implemented only when all variants implement `CryptoRng`.
+//!
 //! ### [rayon] *(requires `"rayon"` crate feature)*
 //!
 //! * [`rayon::ParallelIterator`](https://docs.rs/rayon/1/rayon/iter/trait.ParallelIterator.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/rayon/parallel_iterator.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/rayon/parallel_iterator.expanded.rs)
@@ -705,6 +915,9 @@
 //! ### [serde] *(requires `"serde"` crate feature)*
 //!
 //! * [`serde::Serialize`](https://docs.rs/serde/1/serde/trait.Serialize.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/serde/serialize.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/serde/serialize.expanded.rs)
+//! * [`serde::Deserialize`](https://docs.rs/serde/1/serde/trait.Deserialize.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/serde/deserialize.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/serde/deserialize.expanded.rs)
+//!   * Like `#[serde(untagged)]`, this tries to deserialize each variant's type in declaration order, and returns an error that contains the errors of all variants if none of them match. The input is buffered, so this requires the `alloc` crate, but not the standard library.
+//! * [`serde::Serializer`](https://docs.rs/serde/1/serde/trait.Serializer.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/serde/serializer.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/serde/serializer.expanded.rs)
 //!
 //! ### [tokio v1][tokio1] *(requires `"tokio1"` crate feature)*
 //!
@@ -732,6 +945,25 @@
 //! * [`tokio01::AsyncRead`](https://docs.rs/tokio/0.1/tokio/io/trait.AsyncRead.html)
 //! * [`tokio01::AsyncWrite`](https://docs.rs/tokio/0.1/tokio/io/trait.AsyncWrite.html)
 //!
+//! ### [tower] *(requires `"tower"` crate feature)*
+//!
+//! * [`tower::Service`](https://docs.rs/tower-service/0.3/tower_service/trait.Service.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/tower/service.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/tower/service.expanded.rs)
+//!   * The `Future` associated type is another generated enum that implements `Future`.
+//!   * The generated code refers to `::tower`. If you depend on `tower-service` directly, use `crate(tower = ::tower_service)`.
+//!
+//! ### [tracing-core] *(requires `"tracing_core"` crate feature)*
+//!
+//! * [`tracing_core::Subscriber`](https://docs.rs/tracing-core/0.1/tracing_core/subscriber/trait.Subscriber.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/tracing_core/subscriber.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/tracing_core/subscriber.expanded.rs)
+//!   * `downcast_raw` returns the enum itself for its own `TypeId`, and otherwise forwards to the active variant.
+//!   * The generated code refers to `::tracing_core`, because `tracing` does not re-export `span::Current`, which `Subscriber::current_span` returns. This requires a direct dependency on `tracing-core`. If it is renamed, use `crate(tracing_core = <path>)`, such as `#[enum_derive(tracing_core::Subscriber, crate(tracing_core = ::my_tracing_core))]`.
+//!   * `tracing::Subscriber` is accepted as an alias, but the generated code still refers to `::tracing_core` for the same reason.
+//!
+//! ### [tracing-subscriber] *(requires `"tracing_subscriber"` crate feature)*
+//!
+//! * [`tracing_subscriber::Layer`](https://docs.rs/tracing-subscriber/0.3/tracing_subscriber/layer/trait.Layer.html) - [example](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/tracing_subscriber/layer.rs) | [generated code](https://github.com/taiki-e/auto_enums/blob/HEAD/tests/expand/external/tracing_subscriber/layer.expanded.rs)
+//!   * `downcast_raw` returns the enum itself for its own `TypeId`, and otherwise forwards to the active variant.
+//!   * The generated code also refers to `::tracing_core` for types such as `Metadata` and `Event`, like `tracing_core::Subscriber`.
+//!
 //! ## Inherent methods
 //!
 //! These don't derive traits, but derive inherent methods instead.
@@ -761,6 +993,72 @@
 //!
 //!   * `transpose_err` — convert from `enum<Result<T, E1>,..>` to `Result<T, enum<E1,..>>`
 //!
+//!   * `transpose` — convert from `enum<Poll<T1>,..>` to `Poll<enum<T1,..>>`
+//!
+//!   * `transpose_option_result` — convert from `enum<Option<Result<T1, E1>>,..>` to `Result<Option<enum<T1,..>>, enum<E1,..>>`
+//!
+//!   * `transpose_result_option` — convert from `enum<Result<Option<T1>, E1>,..>` to `Option<Result<enum<T1,..>, enum<E1,..>>>`
+//!
+//! * `Inner` - this derives the following methods.
+//!
+//!   * `into_inner` — convert from `enum<T,..>` to `T`
+//!
+//!   * `as_inner` — convert from `&enum<T,..>` to `&T`
+//!
+//!   * `as_inner_mut` — convert from `&mut enum<T,..>` to `&mut T`
+//!
+//!   * `map_inner` — convert from `enum<T,..>` to `enum<U,..>` by applying a closure to the field of the current variant
+//!
+//!     Examples:
+//!
+//!     ```rust
+//!     use auto_enums::enum_derive;
+//!
+//!     #[enum_derive(Inner)]
+//!     enum Enum<A, B> {
+//!         A(A),
+//!         B(B),
+//!     }
+//!
+//!     let x: Enum<i32, i32> = Enum::B(1);
+//!     let x = x.map_inner(|x| x + 1);
+//!     assert!(match x {
+//!         Enum::B(x) => x == 2,
+//!         Enum::A(_) => false,
+//!     });
+//!     assert_eq!(x.into_inner(), 2);
+//!     ```
+//!
+//! * `Project` - this derives the `project` method, which converts from `Pin<&mut enum<T1,..>>` to an enum of `Pin<&mut T1>`,... This can be used to forward methods that take `self: Pin<&mut Self>` in manual implementations without `unsafe` code. Note that the derives of traits that have such methods (e.g., `Future`) do not use or generate `project`.
+//!
+//!   To keep `project` sound, the derive also implements `Unpin` for the enum only if all of its fields are `Unpin`, so the enum must not implement `Unpin` or `Drop` manually (doing so is a compile error). The projection enum is named `__<Enum>Projection` and has the same visibility as the enum.
+//!
+//!   Examples:
+//!
+//!   ```rust
+//!   use auto_enums::enum_derive;
+//!   use std::pin::Pin;
+//!
+//!   trait Tick {
+//!       fn tick(self: Pin<&mut Self>) -> u8;
+//!   }
+//!
+//!   #[enum_derive(Project)]
+//!   enum Enum<A, B> {
+//!       A(A),
+//!       B(B),
+//!   }
+//!
+//!   impl<A: Tick, B: Tick> Tick for Enum<A, B> {
+//!       fn tick(self: Pin<&mut Self>) -> u8 {
+//!           match self.project() {
+//!               __EnumProjection::A(x) => x.tick(),
+//!               __EnumProjection::B(x) => x.tick(),
+//!           }
+//!       }
+//!   }
+//!   ```
+//!
 //! # Optional features
 //!
 //! * **`std`** *(enabled by default)*
@@ -768,7 +1066,7 @@
 //! * **`ops`**
 //!   * Enable to use `[std|core]::ops`'s `Deref`, `DerefMut`, `Index`, `IndexMut`, and `RangeBounds` traits.
 //! * **`convert`**
-//!   * Enable to use `[std|core]::convert`'s `AsRef` and `AsMut` traits.
+//!   * Enable to use `[std|core]::convert`'s `AsRef` and `AsMut` traits, and `[std|core]::borrow`'s `Borrow` and `BorrowMut` traits.
 //! * **`fmt`**
 //!   * Enable to use `[std|core]::fmt`'s traits other than `Debug`, `Display` and `Write`.
 //! * **`transpose_methods`**
@@ -777,6 +1075,14 @@
 //!   * Enable to use [futures v0.3][futures03] traits.
 //! * **`futures01`**
 //!   * Enable to use [futures v0.1][futures01] traits.
+//! * **`bytes1`**
+//!   * Enable to use [bytes v1][bytes1] traits.
+//! * **`http_body1`**
+//!   * Enable to use [http-body v1][http_body1] traits.
+//! * **`log`**
+//!   * Enable to use [log] traits.
+//! * **`rand_core06`**
+//!   * Enable to use [rand_core v0.6][rand_core06] traits.
 //! * **`rayon`**
 //!   * Enable to use [rayon] traits.
 //! * **`serde`**
@@ -789,8 +1095,22 @@
 //!   * Enable to use [tokio v0.2][tokio02] traits.
 //! * **`tokio01`**
 //!   * Enable to use [tokio v0.1][tokio01] traits.
+//! * **`tower`**
+//!   * Enable to use [tower] traits.
+//! * **`tracing`**
+//!   * Enable to use `instrument` argument of `#[auto_enum]`, which emits [tracing] events.
+//! * **`tracing_core`**
+//!   * Enable to use [tracing-core] traits.
+//! * **`tracing_subscriber`**
+//!   * Enable to use [tracing-subscriber] traits.
+//! * **`coroutine_trait`**
+//!   * Enable to use `[std|core]::ops::Coroutine` trait.
+//!   * Note that this feature is unstable and may cause incompatible changes between patch versions.
 //! * **`generator_trait`**
-//!   * Enable to use `[std|core]::ops::Generator` trait.
+//!   * Enable to use `[std|core]::ops::Generator` trait, which was renamed to `Coroutine` trait since Rust 1.75 nightly. This is an alias of the `coroutine_trait` feature.
+//!   * Note that this feature is unstable and may cause incompatible changes between patch versions.
+//! * **`async_iterator`**
+//!   * Enable to use `[std|core]::async_iter::AsyncIterator` trait.
 //!   * Note that this feature is unstable and may cause incompatible changes between patch versions.
 //! * **`fn_traits`**
 //!   * Enable to use `[std|core]::ops`'s `Fn`, `FnMut`, and `FnOnce` traits.
@@ -840,15 +1160,22 @@
 //! [futures-enum]: https://github.com/taiki-e/futures-enum
 //! [futures01]: https://docs.rs/futures/0.1
 //! [futures03]: https://docs.rs/futures/0.3
+//! [http_body1]: https://docs.rs/http-body/1
 //! [io-enum]: https://github.com/taiki-e/io-enum
 //! [iter-enum]: https://github.com/taiki-e/iter-enum
+//! [log]: https://docs.rs/log/0.4
 //! [proc-macro-derive]: https://doc.rust-lang.org/reference/procedural-macros.html#derive-macros
+//! [rand_core06]: https://docs.rs/rand_core/0.6
 //! [rayon]: https://docs.rs/rayon/1
 //! [serde]: https://docs.rs/serde/1
 //! [tokio01]: https://docs.rs/tokio/0.1
 //! [tokio02]: https://docs.rs/tokio/0.2
 //! [tokio03]: https://docs.rs/tokio/0.3
 //! [tokio1]: https://docs.rs/tokio/1
+//! [tower]: https://docs.rs/tower-service/0.3
+//! [tracing]: https://docs.rs/tracing/0.1
+//! [tracing-core]: https://docs.rs/tracing-core/0.1
+//! [tracing-subscriber]: https://docs.rs/tracing-subscriber/0.3
 
 #![doc(test(
     no_crate_inject,
@@ -863,11 +1190,21 @@
 #![cfg_attr(test, warn(single_use_lifetimes))]
 #![warn(clippy::default_trait_access, clippy::wildcard_imports)]
 
+#[cfg(all(feature = "coroutine_trait", not(feature = "unstable")))]
+compile_error!(
+    "The `coroutine_trait` feature requires the `unstable` feature as an explicit opt-in to unstable features"
+);
+
 #[cfg(all(feature = "generator_trait", not(feature = "unstable")))]
 compile_error!(
     "The `generator_trait` feature requires the `unstable` feature as an explicit opt-in to unstable features"
 );
 
+#[cfg(all(feature = "async_iterator", not(feature = "unstable")))]
+compile_error!(
+    "The `async_iterator` feature requires the `unstable` feature as an explicit opt-in to unstable features"
+);
+
 #[cfg(all(feature = "fn_traits", not(feature = "unstable")))]
 compile_error!(
     "The `fn_traits` feature requires the `unstable` feature as an explicit opt-in to unstable features"
@@ -2,7 +2,19 @@
     feature = "fn_traits",
     feature(proc_macro_hygiene, stmt_expr_attributes, type_ascription)
 )]
-#![cfg_attr(feature = "generator_trait", feature(generator_trait))]
+// `Generator` was renamed to `Coroutine` in Rust 1.75 nightly, and the
+// `generator_trait` and `coroutine_trait` crate features are aliases.
+#![cfg_attr(
+    all(any(feature = "coroutine_trait", feature = "generator_trait"), coroutine_trait_renamed),
+    feature(coroutine_trait)
+)]
+#![cfg_attr(
+    all(
+        any(feature = "coroutine_trait", feature = "generator_trait"),
+        not(coroutine_trait_renamed)
+    ),
+    feature(generator_trait)
+)]
 #![cfg_attr(feature = "fn_traits", feature(fn_traits, unboxed_closures))]
 #![cfg_attr(feature = "trusted_len", feature(trusted_len))]
 #![cfg_attr(not(feature = "std"), no_std)]
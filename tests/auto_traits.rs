@@ -0,0 +1,35 @@
+#![warn(rust_2018_idioms, single_use_lifetimes)]
+#![allow(dead_code)]
+
+use std::panic::UnwindSafe;
+
+use auto_enums::auto_enum;
+
+fn assert_auto_traits<T: Send + Sync + Unpin + UnwindSafe>(x: T) -> T {
+    x
+}
+
+#[test]
+fn auto_traits() {
+    #[auto_enum(Iterator, Send, Sync, Unpin, UnwindSafe)]
+    fn match_(x: usize) -> impl Iterator<Item = i32> {
+        match x {
+            0 => 1..8,
+            n if n > 3 => 2..=10,
+            _ => (0..2).map(|x| x + 1),
+        }
+    }
+    for (i, x) in [28, 3].iter().enumerate() {
+        assert_eq!(assert_auto_traits(match_(i)).sum::<i32>(), *x);
+    }
+
+    // Assertions can be used without other traits.
+    #[auto_enum(Send)]
+    fn send_only(x: usize) -> impl Send {
+        match x {
+            0 => 1,
+            _ => "a",
+        }
+    }
+    assert_auto_traits(send_only(0));
+}
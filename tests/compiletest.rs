@@ -10,6 +10,14 @@
     feature = "tokio02",
     feature = "tokio03",
     feature = "tokio1",
+    feature = "tower",
+    feature = "tracing",
+    feature = "tracing_core",
+    feature = "tracing_subscriber",
+    feature = "http_body1",
+    feature = "log",
+    feature = "rand_core06",
+    feature = "bytes1",
 ))]
 #![warn(rust_2018_idioms, single_use_lifetimes)]
 
@@ -1,10 +1,46 @@
-#![cfg_attr(feature = "generator_trait", feature(generator_trait))]
+// `Generator` was renamed to `Coroutine` in Rust 1.75 nightly, and the
+// `generator_trait` and `coroutine_trait` crate features are aliases.
+#![cfg_attr(
+    all(any(feature = "coroutine_trait", feature = "generator_trait"), coroutine_trait_renamed),
+    feature(coroutine_trait)
+)]
+#![cfg_attr(
+    all(
+        any(feature = "coroutine_trait", feature = "generator_trait"),
+        not(coroutine_trait_renamed)
+    ),
+    feature(generator_trait)
+)]
+#![cfg_attr(feature = "async_iterator", feature(async_iterator))]
 #![cfg_attr(feature = "fn_traits", feature(fn_traits, unboxed_closures))]
 #![cfg_attr(feature = "trusted_len", feature(trusted_len))]
+// `iter::TryFold`, `iter::AdvanceBy`, and `iter::IsEmpty`
+#![cfg_attr(feature = "unstable", feature(try_trait_v2, iter_advance_by, exact_size_is_empty))]
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(rust_2018_idioms, single_use_lifetimes)]
 #![allow(dead_code)]
 
+#[cfg(feature = "bytes1")]
+extern crate bytes1_crate as bytes;
+#[cfg(feature = "futures03")]
+extern crate futures03_crate as futures;
+#[cfg(feature = "http_body1")]
+extern crate http_body1_crate as http_body;
+#[cfg(feature = "log")]
+extern crate log_crate as log;
+#[cfg(feature = "rand_core06")]
+extern crate rand_core06_crate as rand_core;
+#[cfg(feature = "serde")]
+extern crate serde_crate as serde;
+#[cfg(feature = "tower")]
+extern crate tower_service_crate as tower;
+#[cfg(feature = "tracing")]
+extern crate tracing_crate as tracing;
+#[cfg(feature = "tracing_core")]
+extern crate tracing_core_crate as tracing_core;
+#[cfg(feature = "tracing_subscriber")]
+extern crate tracing_subscriber_crate as tracing_subscriber;
+
 use auto_enums::enum_derive;
 
 #[test]
@@ -42,6 +78,8 @@ fn stable() {
         PartialOrd,
         Ord,
         Hash,
+        Hasher,
+        BuildHasher,
         Future
     )]
     enum Stable<A, B> {
@@ -57,7 +95,7 @@ fn stable() {
     }
 
     #[cfg(feature = "convert")]
-    #[enum_derive(AsRef, AsMut)]
+    #[enum_derive(AsRef, AsMut, Borrow<str>, BorrowMut<str>)]
     enum Convert<A, B> {
         A(A),
         B(B),
@@ -106,8 +144,769 @@ fn stable_std() {
     }
 }
 
+#[cfg(all(feature = "std", feature = "convert"))]
+#[test]
+fn transparent_hash() {
+    use std::{
+        collections::{hash_map::DefaultHasher, HashSet},
+        hash::{Hash, Hasher},
+    };
+
+    fn hash<T: ?Sized + Hash>(x: &T) -> u64 {
+        let mut state = DefaultHasher::new();
+        x.hash(&mut state);
+        state.finish()
+    }
+
+    #[enum_derive(transparent::Hash, transparent::PartialEq, Eq, Borrow<str>, AsRef<str>)]
+    enum Key<A, B> {
+        A(A),
+        B(B),
+    }
+
+    let mut set: HashSet<Key<String, Box<str>>> = HashSet::new();
+    set.insert(Key::A("a".to_string()));
+    set.insert(Key::B("b".into()));
+    assert!(set.contains("a"));
+    assert!(set.contains("b"));
+    assert!(!set.contains("c"));
+
+    let a: Key<String, Box<str>> = Key::A("a".to_string());
+    assert!(a == Key::A("a".to_string()));
+    assert!(a != Key::A("b".to_string()));
+    assert_eq!(a.as_ref(), "a");
+
+    // `Borrow` requires that values are equal if and only if the borrowed values
+    // are equal, and that their hash values are the same, even across variants.
+    let b: Key<String, Box<str>> = Key::B("a".into());
+    assert!(a == b);
+    assert!(b != Key::A("b".to_string()));
+    assert_eq!(hash(&a), hash("a"));
+    assert_eq!(hash(&b), hash("a"));
+    assert!(!set.insert(Key::B("a".into())));
+}
+
+/// An iterator whose `next` and `next_back` panic, to check that the derives
+/// forward the other methods to the inner iterator instead of using their
+/// default implementations, which call `next`/`next_back`.
+struct NoNext<I>(I);
+
+impl<I: Iterator> Iterator for NoNext<I> {
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        panic!("`next` is called")
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.0.nth(n)
+    }
+    #[cfg(feature = "unstable")]
+    fn advance_by(&mut self, n: usize) -> Result<(), core::num::NonZeroUsize> {
+        self.0.advance_by(n)
+    }
+    #[cfg(feature = "unstable")]
+    fn try_fold<B, F, R>(&mut self, init: B, f: F) -> R
+    where
+        F: FnMut(B, Self::Item) -> R,
+        R: core::ops::Try<Output = B>,
+    {
+        self.0.try_fold(init, f)
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for NoNext<I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        panic!("`next_back` is called")
+    }
+    #[allow(clippy::incompatible_msrv)] // `nth_back` requires Rust 1.37.
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.0.nth_back(n)
+    }
+    #[cfg(feature = "unstable")]
+    fn advance_back_by(&mut self, n: usize) -> Result<(), core::num::NonZeroUsize> {
+        self.0.advance_back_by(n)
+    }
+    #[cfg(feature = "unstable")]
+    fn try_rfold<B, F, R>(&mut self, init: B, f: F) -> R
+    where
+        F: FnMut(B, Self::Item) -> R,
+        R: core::ops::Try<Output = B>,
+    {
+        self.0.try_rfold(init, f)
+    }
+}
+
+#[test]
+fn iter_nth() {
+    #[enum_derive(Iterator, DoubleEndedIterator)]
+    enum Enum1<A, B> {
+        A(A),
+        B(B),
+    }
+    type Iter = Enum1<NoNext<core::ops::Range<u8>>, NoNext<core::ops::Range<u8>>>;
+
+    let mut it: Iter = Enum1::A(NoNext(0..10));
+    assert_eq!(it.nth(2), Some(2));
+    assert_eq!(it.nth_back(2), Some(7));
+    let mut it: Iter = Enum1::B(NoNext(0..10));
+    assert_eq!(it.nth(3), Some(3));
+    assert_eq!(it.nth_back(0), Some(9));
+    assert_eq!(it.nth(5), None);
+
+    // `Skip` uses `nth` of the inner iterator.
+    let mut it = Iter::A(NoNext(0..10)).skip(4);
+    assert_eq!(it.nth(1), Some(5));
+}
+
+#[test]
+fn project() {
+    use core::pin::Pin;
+
+    trait Tick {
+        fn tick(self: Pin<&mut Self>) -> u8;
+    }
+
+    struct Counter(u8);
+
+    impl Tick for Counter {
+        fn tick(mut self: Pin<&mut Self>) -> u8 {
+            self.0 += 1;
+            self.0
+        }
+    }
+
+    #[enum_derive(Project)]
+    enum Enum<A, B> {
+        A(A),
+        B(B),
+    }
+
+    impl<A: Tick, B: Tick> Tick for Enum<A, B> {
+        fn tick(self: Pin<&mut Self>) -> u8 {
+            match self.project() {
+                __EnumProjection::A(x) => x.tick(),
+                __EnumProjection::B(x) => x.tick(),
+            }
+        }
+    }
+
+    let mut x = Enum::<Counter, Counter>::B(Counter(1));
+    assert_eq!(Pin::new(&mut x).tick(), 2);
+    assert_eq!(Pin::new(&mut x).tick(), 3);
+}
+
+#[cfg(feature = "transpose_methods")]
+#[test]
+fn transpose() {
+    use core::task::Poll;
+
+    #[enum_derive(Transpose, Debug, PartialEq)]
+    enum Enum<A, B> {
+        A(A),
+        B(B),
+    }
+
+    type PollEnum = Enum<Poll<u8>, Poll<i8>>;
+    type OptionResultEnum = Enum<Option<Result<u8, ()>>, Option<Result<i8, bool>>>;
+    type ResultOptionEnum = Enum<Result<Option<u8>, ()>, Result<Option<i8>, bool>>;
+
+    let x: PollEnum = Enum::A(Poll::Ready(1));
+    assert_eq!(x.transpose(), Poll::Ready(Enum::A(1)));
+    let x: PollEnum = Enum::B(Poll::Pending);
+    assert_eq!(x.transpose(), Poll::Pending);
+
+    let x: OptionResultEnum = Enum::A(Some(Ok(1)));
+    assert_eq!(x.transpose_option_result(), Ok(Some(Enum::A(1))));
+    let x: OptionResultEnum = Enum::B(Some(Err(true)));
+    assert_eq!(x.transpose_option_result(), Err(Enum::B(true)));
+    let x: OptionResultEnum = Enum::B(None);
+    assert_eq!(x.transpose_option_result(), Ok(None));
+
+    let x: ResultOptionEnum = Enum::B(Ok(Some(-1)));
+    assert_eq!(x.transpose_result_option(), Some(Ok(Enum::B(-1))));
+    let x: ResultOptionEnum = Enum::A(Err(()));
+    assert_eq!(x.transpose_result_option(), Some(Err(Enum::A(()))));
+    let x: ResultOptionEnum = Enum::A(Ok(None));
+    assert_eq!(x.transpose_result_option(), None);
+}
+
+#[test]
+fn inner() {
+    #[enum_derive(Inner)]
+    enum Enum<A, B> {
+        A(A),
+        B(B),
+    }
+
+    let mut x = Enum::<u8, u8>::B(1);
+    *x.as_inner_mut() += 1;
+    assert_eq!(*x.as_inner(), 2);
+    assert_eq!(x.into_inner(), 2);
+
+    let x = Enum::<u8, u8>::A(1).map_inner(|x| x.to_string());
+    assert!(match x {
+        Enum::A(x) => x == "1",
+        Enum::B(_) => false,
+    });
+    let x = Enum::<u8, u8>::B(1).map_inner(|x| -i16::from(x));
+    assert_eq!(x.map_inner(|x| x * 2).into_inner(), -2);
+
+    #[enum_derive(Inner)]
+    enum WhereEnum<A, B>
+    where
+        A: Iterator,
+        A::Item: Copy,
+    {
+        A(A),
+        B(B),
+    }
+
+    let x = WhereEnum::<_, core::ops::Range<u8>>::A(0..3);
+    assert_eq!(x.into_inner().sum::<u8>(), 3);
+    let x = WhereEnum::<_, core::ops::Range<u8>>::B(0..3);
+    assert_eq!(x.map_inner(|x| x.rev()).into_inner().next(), Some(2));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn build_hasher() {
+    use std::{
+        collections::{
+            hash_map::{DefaultHasher, RandomState},
+            HashMap,
+        },
+        hash::BuildHasherDefault,
+    };
+
+    #[enum_derive(BuildHasher)]
+    enum State<A, B> {
+        A(A),
+        B(B),
+    }
+
+    for state in vec![
+        State::A(RandomState::new()),
+        State::B(BuildHasherDefault::<DefaultHasher>::default()),
+    ] {
+        let mut map = HashMap::with_hasher(state);
+        map.insert("a", 1);
+        assert_eq!(map["a"], 1);
+    }
+}
+
+#[cfg(all(feature = "std", feature = "serde"))]
+#[test]
+fn serde_deserialize() {
+    use serde::de::{
+        value::{Error, SeqDeserializer},
+        Deserialize, IntoDeserializer,
+    };
+
+    #[enum_derive(serde::Deserialize)]
+    #[derive(Debug, PartialEq)]
+    enum Value<A, B, C, D> {
+        Byte(A),
+        Int(B),
+        Str(C),
+        Seq(D),
+    }
+    type V = Value<u8, u128, String, Vec<u8>>;
+    fn de<T: IntoDeserializer<'static, Error>>(v: T) -> Result<V, Error> {
+        V::deserialize(v.into_deserializer())
+    }
+
+    // Variants are tried in declaration order.
+    assert_eq!(de(1u64), Ok(Value::Byte(1)));
+    assert_eq!(de(300u64), Ok(Value::Int(300)));
+    assert_eq!(de(u128::max_value()), Ok(Value::Int(u128::max_value())));
+    assert_eq!(de("a"), Ok(Value::Str("a".into())));
+    let seq = SeqDeserializer::<_, Error>::new(vec![1u8, 2].into_iter());
+    assert_eq!(V::deserialize(seq), Ok(Value::Seq(vec![1, 2])));
+
+    let err = de(true).unwrap_err().to_string();
+    assert!(err.starts_with("data did not match any variant of enum Value:"), "{}", err);
+    for variant in &["Byte", "Int", "Str", "Seq"] {
+        assert!(err.contains(&format!("variant `{}`: invalid type: boolean `true`", variant)));
+    }
+}
+
+#[cfg(all(feature = "std", feature = "serde"))]
+#[test]
+fn serde_serializer() {
+    use std::collections::BTreeMap;
+
+    use serde::ser::{Serialize, SerializeStruct, SerializeStructVariant, Serializer};
+
+    #[enum_derive(serde::Serializer)]
+    enum Json<A, B> {
+        Compact(A),
+        Pretty(B),
+    }
+
+    struct StructVariant;
+    impl Serialize for StructVariant {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut s = serializer.serialize_struct_variant("E", 0, "V", 2)?;
+            s.serialize_field("x", &1)?;
+            s.serialize_field("y", &2)?;
+            s.end()
+        }
+    }
+
+    struct Struct;
+    impl Serialize for Struct {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut map = BTreeMap::new();
+            map.insert("a", 1);
+            let mut s = serializer.serialize_struct("Struct", 3)?;
+            s.serialize_field("seq", &[1, 2][..])?;
+            s.serialize_field("map", &map)?;
+            s.serialize_field("variant", &StructVariant)?;
+            s.end()
+        }
+    }
+
+    fn to_string(pretty: bool) -> String {
+        let mut buf = Vec::new();
+        let (mut compact_ser, mut pretty_ser);
+        let serializer = if pretty {
+            pretty_ser = serde_json::Serializer::pretty(&mut buf);
+            Json::Pretty(&mut pretty_ser)
+        } else {
+            compact_ser = serde_json::Serializer::new(&mut buf);
+            Json::Compact(&mut compact_ser)
+        };
+        Struct.serialize(serializer).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    assert_eq!(to_string(false), r#"{"seq":[1,2],"map":{"a":1},"variant":{"V":{"x":1,"y":2}}}"#);
+    assert_eq!(to_string(true), serde_json::to_string_pretty(&Struct).unwrap());
+    assert!(to_string(true).starts_with("{\n  \"seq\": [\n    1,"));
+}
+
+// `Waker::noop` is stable since Rust 1.85.
+#[cfg(all(feature = "std", feature = "tower"))]
+#[rustversion::since(1.85)]
+#[test]
+fn tower_service() {
+    use std::{
+        future::{self, Future, Ready},
+        pin::Pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use tower::Service;
+
+    #[enum_derive(tower::Service)]
+    enum Either<A, B> {
+        A(A),
+        B(B),
+    }
+
+    struct Double;
+    impl Service<u32> for Double {
+        type Response = u32;
+        type Error = ();
+        type Future = Ready<Result<u32, ()>>;
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), ()>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: u32) -> Self::Future {
+            future::ready(Ok(req * 2))
+        }
+    }
+
+    struct Increment;
+    impl Service<u32> for Increment {
+        type Response = u32;
+        type Error = ();
+        type Future = Pin<Box<dyn Future<Output = Result<u32, ()>>>>;
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), ()>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: u32) -> Self::Future {
+            Box::pin(async move { Ok(req + 1) })
+        }
+    }
+
+    let cx = &mut Context::from_waker(Waker::noop());
+    for (mut service, expected) in vec![(Either::A(Double), 4), (Either::B(Increment), 3)] {
+        assert_eq!(service.poll_ready(cx), Poll::Ready(Ok(())));
+        let mut future = service.call(2);
+        assert_eq!(Pin::new(&mut future).poll(cx), Poll::Ready(Ok(expected)));
+    }
+}
+
+#[cfg(all(feature = "std", feature = "bytes1"))]
+#[test]
+fn bytes1() {
+    use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+    #[enum_derive(bytes1::Buf)]
+    enum Either<A, B> {
+        A(A),
+        B(B),
+    }
+
+    #[enum_derive(bytes1::BufMut)]
+    enum EitherMut<A, B> {
+        A(A),
+        B(B),
+    }
+
+    for mut buf in vec![
+        Either::A(Bytes::from_static(b"hello world")),
+        Either::B(Bytes::from_static(b"hello ").chain(Bytes::from_static(b"world"))),
+    ] {
+        assert_eq!(buf.remaining(), 11);
+        assert_eq!(buf.copy_to_bytes(6), "hello ");
+        assert_eq!(buf.chunk(), b"world");
+        buf.advance(5);
+        assert!(!buf.has_remaining());
+    }
+
+    // The default implementation of `chunks_vectored` only fills one slice.
+    let chain = Bytes::from_static(b"hello ").chain(Bytes::from_static(b"world"));
+    let buf = Either::<Bytes, _>::B(chain);
+    let mut dst = [std::io::IoSlice::new(&[]); 2];
+    assert_eq!(buf.chunks_vectored(&mut dst), 2);
+    assert_eq!(&*dst[1], b"world");
+
+    for mut buf in vec![EitherMut::A(Vec::new()), EitherMut::B(BytesMut::new())] {
+        buf.put_slice(b"hello ");
+        buf.put(Bytes::from_static(b"world"));
+        buf.put_u8(b'!');
+        let buf = match buf {
+            EitherMut::A(buf) => buf,
+            EitherMut::B(buf) => buf.to_vec(),
+        };
+        assert_eq!(buf, b"hello world!");
+    }
+}
+
+#[cfg(all(feature = "std", feature = "futures03"))]
+#[test]
+fn futures03_fused() {
+    use futures::{
+        future::{self, FusedFuture, FutureExt},
+        stream::{self, FusedStream, Stream, StreamExt},
+        task::{noop_waker_ref, Context, Poll},
+    };
+
+    #[enum_derive(Future, futures03::FusedFuture)]
+    enum Future1<A, B> {
+        A(A),
+        B(B),
+    }
+
+    #[enum_derive(futures03::Stream, futures03::FusedStream)]
+    enum Stream1<A, B> {
+        A(A),
+        B(B),
+    }
+
+    let cx = &mut Context::from_waker(noop_waker_ref());
+
+    let mut fut = Future1::<_, future::Pending<i32>>::A(future::ready(1).fuse());
+    assert!(!fut.is_terminated());
+    assert_eq!(fut.poll_unpin(cx), Poll::Ready(1));
+    assert!(fut.is_terminated());
+    assert!(Future1::<future::Fuse<future::Ready<i32>>, _>::B(future::pending()).is_terminated());
+
+    let mut stream = Stream1::<_, stream::Empty<i32>>::A(stream::iter(vec![1, 2]).fuse());
+    assert_eq!(stream.size_hint(), (2, Some(2)));
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(1)));
+    assert_eq!(stream.size_hint(), (1, Some(1)));
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(2)));
+    assert!(!stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+    assert!(stream.is_terminated());
+    let stream =
+        Stream1::<stream::Fuse<stream::Iter<std::vec::IntoIter<i32>>>, _>::B(stream::empty());
+    assert_eq!(stream.size_hint(), (0, Some(0)));
+}
+
+#[cfg(all(feature = "std", feature = "http_body1"))]
+#[rustversion::since(1.85)]
+#[test]
+fn http_body1() {
+    use std::{
+        pin::Pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use http_body::Body;
+
+    #[enum_derive(http_body1::Body)]
+    enum Either<A, B> {
+        A(A),
+        B(B),
+    }
+
+    let cx = &mut Context::from_waker(Waker::noop());
+    for mut body in vec![Either::A(String::from("abc")), Either::B(Box::pin(String::from("abc")))] {
+        assert!(!body.is_end_stream());
+        assert_eq!(body.size_hint().exact(), Some(3));
+        match Pin::new(&mut body).poll_frame(cx) {
+            Poll::Ready(Some(Ok(frame))) => assert_eq!(frame.into_data().unwrap(), "abc"),
+            _ => panic!(),
+        }
+        assert!(body.is_end_stream());
+    }
+}
+
+#[cfg(feature = "rand_core06")]
+#[test]
+fn rand_core06() {
+    use rand_core::{impls, CryptoRng, RngCore};
+
+    #[enum_derive(rand_core06::RngCore, rand_core06::CryptoRng)]
+    enum Either<A, B> {
+        A(A),
+        B(B),
+    }
+
+    struct Counter(u64);
+    impl RngCore for Counter {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0 += 1;
+            self.0
+        }
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            impls::fill_bytes_via_next(self, dest);
+        }
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+    impl CryptoRng for Counter {}
+
+    struct Zero;
+    impl RngCore for Zero {
+        fn next_u32(&mut self) -> u32 {
+            0
+        }
+        fn next_u64(&mut self) -> u64 {
+            0
+        }
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            dest.iter_mut().for_each(|b| *b = 0);
+        }
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    fn crypto_rng<T: CryptoRng + RngCore>(rng: &mut T) -> u64 {
+        rng.next_u64()
+    }
+
+    let mut rng: Either<Counter, Zero> = Either::A(Counter(0));
+    assert_eq!(rng.next_u32(), 1);
+    assert_eq!(rng.next_u64(), 2);
+    let mut buf = [0; 4];
+    rng.try_fill_bytes(&mut buf).unwrap();
+    assert_eq!(buf, [3, 0, 0, 0]);
+
+    let mut rng: Either<Counter, Zero> = Either::B(Zero);
+    let mut buf = [1; 4];
+    rng.fill_bytes(&mut buf);
+    assert_eq!(buf, [0; 4]);
+    assert_eq!(rng.next_u64(), 0);
+
+    let mut rng: Either<Counter, Counter> = Either::B(Counter(10));
+    assert_eq!(crypto_rng(&mut rng), 11);
+}
+
+#[cfg(all(
+    feature = "std",
+    feature = "tracing",
+    feature = "tracing_core",
+    feature = "tracing_subscriber"
+))]
+#[test]
+fn tracing() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use tracing::{subscriber::NoSubscriber, Dispatch, Event, Subscriber};
+    use tracing_subscriber::{
+        layer::{Context, Layered, SubscriberExt},
+        Layer, Registry,
+    };
+
+    // `tracing::Subscriber` is an alias of `tracing_core::Subscriber`.
+    #[enum_derive(tracing_subscriber::Layer, tracing::Subscriber)]
+    enum Either<A, B> {
+        A(A),
+        B(B),
+    }
+
+    struct Count(Arc<AtomicUsize>);
+    impl<S: Subscriber> Layer<S> for Count {
+        fn on_event(&self, _: &Event<'_>, _: Context<'_, S>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    struct Noop;
+    impl<S: Subscriber> Layer<S> for Noop {}
+
+    // Layer
+    let count = Arc::new(AtomicUsize::new(0));
+    let layer: Either<Count, Noop> = Either::A(Count(count.clone()));
+    tracing::subscriber::with_default(tracing_subscriber::registry().with(layer), || {
+        tracing::info!("event");
+    });
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+
+    let layer: Either<Count, Noop> = Either::B(Noop);
+    let dispatch = Dispatch::new(tracing_subscriber::registry().with(layer));
+    assert!(dispatch.downcast_ref::<Either<Count, Noop>>().is_some());
+    assert!(dispatch.downcast_ref::<Noop>().is_some());
+    assert!(dispatch.downcast_ref::<Count>().is_none());
+
+    // Subscriber
+    let count = Arc::new(AtomicUsize::new(0));
+    let subscriber = tracing_subscriber::registry().with(Count(count.clone()));
+    let subscriber: Either<_, NoSubscriber> = Either::A(subscriber);
+    tracing::subscriber::with_default(subscriber, || {
+        tracing::info!("event");
+    });
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+
+    let subscriber: Either<Layered<Count, Registry>, _> = Either::B(NoSubscriber::new());
+    let dispatch = Dispatch::new(subscriber);
+    tracing::dispatcher::with_default(&dispatch, || {
+        tracing::info!("event");
+    });
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    assert!(dispatch.downcast_ref::<NoSubscriber>().is_some());
+    assert!(dispatch.downcast_ref::<Count>().is_none());
+}
+
+#[cfg(feature = "log")]
+#[test]
+fn log() {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use log::{Level, Log, Metadata, Record};
+
+    #[enum_derive(log::Log)]
+    enum Either<A, B> {
+        A(A),
+        B(B),
+    }
+
+    struct Count(AtomicUsize);
+    impl Log for Count {
+        fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+            metadata.level() <= Level::Info
+        }
+        fn log(&self, record: &Record<'_>) {
+            if self.enabled(record.metadata()) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+        fn flush(&self) {}
+    }
+
+    struct Nop;
+    impl Log for Nop {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            false
+        }
+        fn log(&self, _: &Record<'_>) {}
+        fn flush(&self) {}
+    }
+
+    let info = Record::builder().level(Level::Info).build();
+    let debug = Record::builder().level(Level::Debug).build();
+
+    let logger: Either<Count, Nop> = Either::A(Count(AtomicUsize::new(0)));
+    assert!(logger.enabled(info.metadata()));
+    assert!(!logger.enabled(debug.metadata()));
+    logger.log(&info);
+    logger.log(&debug);
+    logger.flush();
+    match &logger {
+        Either::A(count) => assert_eq!(count.0.load(Ordering::SeqCst), 1),
+        Either::B(_) => unreachable!(),
+    }
+
+    let logger: Either<Count, Nop> = Either::B(Nop);
+    assert!(!logger.enabled(info.metadata()));
+    logger.log(&info);
+}
+
+#[cfg(all(feature = "std", unix))]
+#[test]
+fn fd() {
+    use std::{fs::File, net::TcpStream, os::unix::io::AsRawFd};
+
+    #[enum_derive(Read, Write, AsRawFd, IntoRawFd)]
+    enum Fd<A, B> {
+        A(A),
+        B(B),
+    }
+
+    let file = File::open("Cargo.toml").unwrap();
+    let raw = file.as_raw_fd();
+    let fd: Fd<File, TcpStream> = Fd::A(file);
+    assert_eq!(fd.as_raw_fd(), raw);
+}
+
+#[cfg(feature = "std")]
+#[rustversion::since(1.63)]
+#[test]
+fn as_fd() {
+    #[enum_derive(AsFd)]
+    enum Enum1<A, B> {
+        A(A),
+        B(B),
+    }
+}
+
+#[rustversion::since(1.64)]
+#[test]
+fn into_future() {
+    #[enum_derive(IntoFuture)]
+    pub enum Enum1<A, B> {
+        A(A),
+        B(B),
+    }
+}
+
+// `core::error::Error` is available without the `std` feature.
+#[rustversion::since(1.81)]
+#[test]
+fn core_error() {
+    #[enum_derive(Error)]
+    enum Enum1<A, B> {
+        A(A),
+        B(B),
+    }
+}
+
 // nightly
 
+#[cfg(feature = "coroutine_trait")]
+#[test]
+fn coroutine_trait() {
+    #[enum_derive(Coroutine)]
+    enum Enum1<A, B> {
+        A(A),
+        B(B),
+    }
+}
+
 #[cfg(feature = "generator_trait")]
 #[test]
 fn generator_trait() {
@@ -118,6 +917,16 @@ fn generator_trait() {
     }
 }
 
+#[cfg(feature = "async_iterator")]
+#[test]
+fn async_iterator() {
+    #[enum_derive(AsyncIterator)]
+    enum Enum1<A, B> {
+        A(A),
+        B(B),
+    }
+}
+
 #[cfg(feature = "fn_traits")]
 #[test]
 fn fn_traits() {
@@ -137,3 +946,82 @@ fn trusted_len() {
         B(B),
     }
 }
+
+#[cfg(feature = "unstable")]
+#[test]
+#[allow(clippy::iter_nth_zero)] // `next` of `NoNext` panics.
+fn iter_advance_by() {
+    use core::num::NonZeroUsize;
+
+    #[enum_derive(Iterator, DoubleEndedIterator, iter::AdvanceBy)]
+    enum Enum1<A, B> {
+        A(A),
+        B(B),
+    }
+    type Iter = Enum1<NoNext<core::ops::Range<u8>>, NoNext<core::ops::Range<u8>>>;
+
+    let mut it: Iter = Enum1::A(NoNext(0..10));
+    assert_eq!(it.advance_by(3), Ok(()));
+    assert_eq!(it.nth(0), Some(3));
+    let mut it: Iter = Enum1::B(NoNext(0..10));
+    assert_eq!(it.advance_back_by(3), Ok(()));
+    assert_eq!(it.nth_back(0), Some(6));
+    assert_eq!(it.advance_by(10), Err(NonZeroUsize::new(4).unwrap()));
+}
+
+#[cfg(feature = "unstable")]
+#[test]
+fn iter_try_fold() {
+    #[enum_derive(Iterator, DoubleEndedIterator, iter::TryFold)]
+    enum Enum1<A, B> {
+        A(A),
+        B(B),
+    }
+    type Iter = Enum1<NoNext<core::ops::Range<i32>>, NoNext<core::ops::Range<i32>>>;
+
+    let mut it: Iter = Enum1::A(NoNext(1..5));
+    assert_eq!(it.try_fold(0, |acc, x| if x < 3 { Some(acc + x) } else { None }), None);
+    assert_eq!(it.try_fold(0, |acc, x| Some(acc + x)), Some(4));
+    let mut it: Iter = Enum1::B(NoNext(1..5));
+    assert_eq!(it.try_rfold(0, |acc, x| Some(acc * 10 + x)), Some(4321));
+
+    // `find`, `rfind` and `find_map` are no longer forwarded directly,
+    // but still short-circuit through the forwarded `try_fold`/`try_rfold`.
+    let mut it: Iter = Enum1::A(NoNext(1..5));
+    assert_eq!(it.find(|&x| x % 2 == 0), Some(2));
+    assert_eq!(it.rfind(|&x| x % 2 == 1), Some(3));
+    let mut it: Iter = Enum1::B(NoNext(1..5));
+    assert_eq!(it.find_map(|x| if x > 2 { Some(x * 10) } else { None }), Some(30));
+}
+
+#[cfg(feature = "unstable")]
+#[test]
+fn exact_size_is_empty() {
+    // `len` panics, so `is_empty` must be forwarded to the inner `is_empty`.
+    struct IsEmptyOnly(bool);
+
+    impl Iterator for IsEmptyOnly {
+        type Item = ();
+        fn next(&mut self) -> Option<Self::Item> {
+            None
+        }
+    }
+
+    impl ExactSizeIterator for IsEmptyOnly {
+        fn len(&self) -> usize {
+            panic!("`is_empty` is not forwarded")
+        }
+        fn is_empty(&self) -> bool {
+            self.0
+        }
+    }
+
+    #[enum_derive(ExactSizeIterator, iter::IsEmpty)]
+    enum Enum1<A, B> {
+        A(A),
+        B(B),
+    }
+
+    assert!(Enum1::<_, IsEmptyOnly>::A(IsEmptyOnly(true)).is_empty());
+    assert!(!Enum1::<IsEmptyOnly, _>::B(IsEmptyOnly(false)).is_empty());
+}
@@ -0,0 +1,132 @@
+use auto_enums::auto_enum;
+fn foo(x: i32) -> impl Iterator<Item = i32> {
+    #[allow(non_camel_case_types)]
+    enum __Enum16237125260846436090<__Variant0, __Variant1> {
+        __Variant0(__Variant0),
+        __Variant1(__Variant1),
+    }
+    impl<__Variant0, __Variant1> ::core::iter::Iterator
+    for __Enum16237125260846436090<__Variant0, __Variant1>
+    where
+        __Variant0: ::core::iter::Iterator,
+        __Variant1: ::core::iter::Iterator<
+            Item = <__Variant0 as ::core::iter::Iterator>::Item,
+        >,
+    {
+        type Item = <__Variant0 as ::core::iter::Iterator>::Item;
+        #[inline]
+        fn next(&mut self) -> ::core::option::Option<Self::Item> {
+            match self {
+                __Enum16237125260846436090::__Variant0(x) => {
+                    ::core::iter::Iterator::next(x)
+                }
+                __Enum16237125260846436090::__Variant1(x) => {
+                    ::core::iter::Iterator::next(x)
+                }
+            }
+        }
+        #[inline]
+        fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
+            match self {
+                __Enum16237125260846436090::__Variant0(x) => {
+                    ::core::iter::Iterator::size_hint(x)
+                }
+                __Enum16237125260846436090::__Variant1(x) => {
+                    ::core::iter::Iterator::size_hint(x)
+                }
+            }
+        }
+        #[inline]
+        fn count(self) -> usize {
+            match self {
+                __Enum16237125260846436090::__Variant0(x) => {
+                    ::core::iter::Iterator::count(x)
+                }
+                __Enum16237125260846436090::__Variant1(x) => {
+                    ::core::iter::Iterator::count(x)
+                }
+            }
+        }
+        #[inline]
+        fn last(self) -> ::core::option::Option<Self::Item> {
+            match self {
+                __Enum16237125260846436090::__Variant0(x) => {
+                    ::core::iter::Iterator::last(x)
+                }
+                __Enum16237125260846436090::__Variant1(x) => {
+                    ::core::iter::Iterator::last(x)
+                }
+            }
+        }
+        #[inline]
+        fn nth(&mut self, n: usize) -> ::core::option::Option<Self::Item> {
+            match self {
+                __Enum16237125260846436090::__Variant0(x) => {
+                    ::core::iter::Iterator::nth(x, n)
+                }
+                __Enum16237125260846436090::__Variant1(x) => {
+                    ::core::iter::Iterator::nth(x, n)
+                }
+            }
+        }
+        #[inline]
+        #[must_use = "if you really need to exhaust the iterator, consider `.for_each(drop)` instead"]
+        fn collect<__U: ::core::iter::FromIterator<Self::Item>>(self) -> __U {
+            match self {
+                __Enum16237125260846436090::__Variant0(x) => {
+                    ::core::iter::Iterator::collect(x)
+                }
+                __Enum16237125260846436090::__Variant1(x) => {
+                    ::core::iter::Iterator::collect(x)
+                }
+            }
+        }
+        #[inline]
+        fn fold<__U, __F>(self, init: __U, f: __F) -> __U
+        where
+            __F: ::core::ops::FnMut(__U, Self::Item) -> __U,
+        {
+            match self {
+                __Enum16237125260846436090::__Variant0(x) => {
+                    ::core::iter::Iterator::fold(x, init, f)
+                }
+                __Enum16237125260846436090::__Variant1(x) => {
+                    ::core::iter::Iterator::fold(x, init, f)
+                }
+            }
+        }
+        #[inline]
+        fn find<__P>(&mut self, predicate: __P) -> ::core::option::Option<Self::Item>
+        where
+            __P: ::core::ops::FnMut(&Self::Item) -> bool,
+        {
+            match self {
+                __Enum16237125260846436090::__Variant0(x) => {
+                    ::core::iter::Iterator::find(x, predicate)
+                }
+                __Enum16237125260846436090::__Variant1(x) => {
+                    ::core::iter::Iterator::find(x, predicate)
+                }
+            }
+        }
+        #[inline]
+        fn find_map<__U, __F>(&mut self, f: __F) -> ::core::option::Option<__U>
+        where
+            __F: ::core::ops::FnMut(Self::Item) -> ::core::option::Option<__U>,
+        {
+            match self {
+                __Enum16237125260846436090::__Variant0(x) => {
+                    ::core::iter::Iterator::find_map(x, f)
+                }
+                __Enum16237125260846436090::__Variant1(x) => {
+                    ::core::iter::Iterator::find_map(x, f)
+                }
+            }
+        }
+    }
+    match x {
+        0 => __Enum16237125260846436090::__Variant0(1..10),
+        _ => __Enum16237125260846436090::__Variant1(5..=10),
+    }
+}
+fn main() {}
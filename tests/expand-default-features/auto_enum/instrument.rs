@@ -0,0 +1,12 @@
+use auto_enums::auto_enum;
+
+// `instrument` generates nothing if the `tracing` crate feature is disabled.
+#[auto_enum(Iterator, instrument)]
+fn foo(x: i32) -> impl Iterator<Item = i32> {
+    match x {
+        0 => 1..10,
+        _ => 5..=10,
+    }
+}
+
+fn main() {}
@@ -38,6 +38,13 @@ fn foo(x: i32) -> impl Iterator<Item = i32> {
             }
         }
         #[inline]
+        fn nth(&mut self, n: usize) -> ::core::option::Option<Self::Item> {
+            match self {
+                __Enum1::__T1(x) => ::core::iter::Iterator::nth(x, n),
+                __Enum1::__T2(x) => ::core::iter::Iterator::nth(x, n),
+            }
+        }
+        #[inline]
         #[must_use = "if you really need to exhaust the iterator, consider `.for_each(drop)` instead"]
         fn collect<__U: ::core::iter::FromIterator<Self::Item>>(self) -> __U {
             match self {
@@ -0,0 +1,61 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+impl<A, B> ::bytes::Buf for Enum<A, B>
+where
+    A: ::bytes::Buf,
+    B: ::bytes::Buf,
+{
+    #[inline]
+    fn remaining(&self) -> usize {
+        match self {
+            Enum::A(x) => ::bytes::Buf::remaining(x),
+            Enum::B(x) => ::bytes::Buf::remaining(x),
+        }
+    }
+    #[inline]
+    fn chunk(&self) -> &[u8] {
+        match self {
+            Enum::A(x) => ::bytes::Buf::chunk(x),
+            Enum::B(x) => ::bytes::Buf::chunk(x),
+        }
+    }
+    #[inline]
+    fn chunks_vectored<'__a>(&'__a self, dst: &mut [::std::io::IoSlice<'__a>]) -> usize {
+        match self {
+            Enum::A(x) => ::bytes::Buf::chunks_vectored(x, dst),
+            Enum::B(x) => ::bytes::Buf::chunks_vectored(x, dst),
+        }
+    }
+    #[inline]
+    fn advance(&mut self, cnt: usize) {
+        match self {
+            Enum::A(x) => ::bytes::Buf::advance(x, cnt),
+            Enum::B(x) => ::bytes::Buf::advance(x, cnt),
+        }
+    }
+    #[inline]
+    fn has_remaining(&self) -> bool {
+        match self {
+            Enum::A(x) => ::bytes::Buf::has_remaining(x),
+            Enum::B(x) => ::bytes::Buf::has_remaining(x),
+        }
+    }
+    #[inline]
+    fn copy_to_slice(&mut self, dst: &mut [u8]) {
+        match self {
+            Enum::A(x) => ::bytes::Buf::copy_to_slice(x, dst),
+            Enum::B(x) => ::bytes::Buf::copy_to_slice(x, dst),
+        }
+    }
+    #[inline]
+    fn copy_to_bytes(&mut self, len: usize) -> ::bytes::Bytes {
+        match self {
+            Enum::A(x) => ::bytes::Buf::copy_to_bytes(x, len),
+            Enum::B(x) => ::bytes::Buf::copy_to_bytes(x, len),
+        }
+    }
+}
+fn main() {}
@@ -0,0 +1,62 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+#[allow(unsafe_code)]
+unsafe impl<A, B> ::bytes::BufMut for Enum<A, B>
+where
+    A: ::bytes::BufMut,
+    B: ::bytes::BufMut,
+{
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        match self {
+            Enum::A(x) => ::bytes::BufMut::remaining_mut(x),
+            Enum::B(x) => ::bytes::BufMut::remaining_mut(x),
+        }
+    }
+    #[inline]
+    fn has_remaining_mut(&self) -> bool {
+        match self {
+            Enum::A(x) => ::bytes::BufMut::has_remaining_mut(x),
+            Enum::B(x) => ::bytes::BufMut::has_remaining_mut(x),
+        }
+    }
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut ::bytes::buf::UninitSlice {
+        match self {
+            Enum::A(x) => ::bytes::BufMut::chunk_mut(x),
+            Enum::B(x) => ::bytes::BufMut::chunk_mut(x),
+        }
+    }
+    #[inline]
+    fn put<__T>(&mut self, src: __T)
+    where
+        __T: ::bytes::Buf,
+        Self: ::core::marker::Sized,
+    {
+        match self {
+            Enum::A(x) => ::bytes::BufMut::put(x, src),
+            Enum::B(x) => ::bytes::BufMut::put(x, src),
+        }
+    }
+    #[inline]
+    fn put_slice(&mut self, src: &[u8]) {
+        match self {
+            Enum::A(x) => ::bytes::BufMut::put_slice(x, src),
+            Enum::B(x) => ::bytes::BufMut::put_slice(x, src),
+        }
+    }
+    #[inline]
+    #[allow(unused_unsafe)]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        unsafe {
+            match self {
+                Enum::A(x) => ::bytes::BufMut::advance_mut(x, cnt),
+                Enum::B(x) => ::bytes::BufMut::advance_mut(x, cnt),
+            }
+        }
+    }
+}
+fn main() {}
@@ -0,0 +1,52 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+#[allow(unsafe_code)]
+impl<A, B> ::http_body::Body for Enum<A, B>
+where
+    A: ::http_body::Body,
+    B: ::http_body::Body<
+        Data = <A as ::http_body::Body>::Data,
+        Error = <A as ::http_body::Body>::Error,
+    >,
+{
+    type Data = <A as ::http_body::Body>::Data;
+    type Error = <A as ::http_body::Body>::Error;
+    #[inline]
+    fn poll_frame(
+        self: ::core::pin::Pin<&mut Self>,
+        cx: &mut ::core::task::Context<'_>,
+    ) -> ::core::task::Poll<
+        ::core::option::Option<
+            ::core::result::Result<::http_body::Frame<Self::Data>, Self::Error>,
+        >,
+    > {
+        unsafe {
+            match self.get_unchecked_mut() {
+                Enum::A(x) => {
+                    ::http_body::Body::poll_frame(::core::pin::Pin::new_unchecked(x), cx)
+                }
+                Enum::B(x) => {
+                    ::http_body::Body::poll_frame(::core::pin::Pin::new_unchecked(x), cx)
+                }
+            }
+        }
+    }
+    #[inline]
+    fn is_end_stream(&self) -> bool {
+        match self {
+            Enum::A(x) => ::http_body::Body::is_end_stream(x),
+            Enum::B(x) => ::http_body::Body::is_end_stream(x),
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> ::http_body::SizeHint {
+        match self {
+            Enum::A(x) => ::http_body::Body::size_hint(x),
+            Enum::B(x) => ::http_body::Body::size_hint(x),
+        }
+    }
+}
+fn main() {}
@@ -0,0 +1,33 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+impl<A, B> ::log::Log for Enum<A, B>
+where
+    A: ::log::Log,
+    B: ::log::Log,
+{
+    #[inline]
+    fn enabled(&self, metadata: &::log::Metadata<'_>) -> bool {
+        match self {
+            Enum::A(x) => ::log::Log::enabled(x, metadata),
+            Enum::B(x) => ::log::Log::enabled(x, metadata),
+        }
+    }
+    #[inline]
+    fn log(&self, record: &::log::Record<'_>) {
+        match self {
+            Enum::A(x) => ::log::Log::log(x, record),
+            Enum::B(x) => ::log::Log::log(x, record),
+        }
+    }
+    #[inline]
+    fn flush(&self) {
+        match self {
+            Enum::A(x) => ::log::Log::flush(x),
+            Enum::B(x) => ::log::Log::flush(x),
+        }
+    }
+}
+fn main() {}
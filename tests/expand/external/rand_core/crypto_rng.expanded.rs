@@ -0,0 +1,11 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+impl<A, B> ::rand_core::CryptoRng for Enum<A, B>
+where
+    A: ::rand_core::CryptoRng,
+    B: ::rand_core::CryptoRng,
+{}
+fn main() {}
@@ -0,0 +1,43 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+impl<A, B> ::rand_core::RngCore for Enum<A, B>
+where
+    A: ::rand_core::RngCore,
+    B: ::rand_core::RngCore,
+{
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            Enum::A(x) => ::rand_core::RngCore::next_u32(x),
+            Enum::B(x) => ::rand_core::RngCore::next_u32(x),
+        }
+    }
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            Enum::A(x) => ::rand_core::RngCore::next_u64(x),
+            Enum::B(x) => ::rand_core::RngCore::next_u64(x),
+        }
+    }
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            Enum::A(x) => ::rand_core::RngCore::fill_bytes(x, dest),
+            Enum::B(x) => ::rand_core::RngCore::fill_bytes(x, dest),
+        }
+    }
+    #[inline]
+    fn try_fill_bytes(
+        &mut self,
+        dest: &mut [u8],
+    ) -> ::core::result::Result<(), ::rand_core::Error> {
+        match self {
+            Enum::A(x) => ::rand_core::RngCore::try_fill_bytes(x, dest),
+            Enum::B(x) => ::rand_core::RngCore::try_fill_bytes(x, dest),
+        }
+    }
+}
+fn main() {}
@@ -0,0 +1,679 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+const _: () = {
+    extern crate alloc as __alloc;
+    enum __Content<'de> {
+        Bool(bool),
+        U64(u64),
+        I64(i64),
+        U128(u128),
+        I128(i128),
+        F64(f64),
+        String(__alloc::string::String),
+        Str(&'de str),
+        ByteBuf(__alloc::vec::Vec<u8>),
+        Bytes(&'de [u8]),
+        None,
+        Some(__alloc::boxed::Box<__Content<'de>>),
+        Unit,
+        Newtype(__alloc::boxed::Box<__Content<'de>>),
+        Seq(__alloc::vec::Vec<__Content<'de>>),
+        Map(__alloc::vec::Vec<(__Content<'de>, __Content<'de>)>),
+    }
+    impl<'de> ::serde::de::Deserialize<'de> for __Content<'de> {
+        fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+        where
+            D: ::serde::de::Deserializer<'de>,
+        {
+            ::serde::de::Deserializer::deserialize_any(
+                deserializer,
+                __ContentVisitor(::core::marker::PhantomData),
+            )
+        }
+    }
+    struct __ContentVisitor<'de>(::core::marker::PhantomData<__Content<'de>>);
+    impl<'de> ::serde::de::Visitor<'de> for __ContentVisitor<'de> {
+        type Value = __Content<'de>;
+        fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.write_str("any value")
+        }
+        fn visit_bool<E>(self, v: bool) -> ::core::result::Result<Self::Value, E> {
+            ::core::result::Result::Ok(__Content::Bool(v))
+        }
+        fn visit_u64<E>(self, v: u64) -> ::core::result::Result<Self::Value, E> {
+            ::core::result::Result::Ok(__Content::U64(v))
+        }
+        fn visit_i64<E>(self, v: i64) -> ::core::result::Result<Self::Value, E> {
+            ::core::result::Result::Ok(__Content::I64(v))
+        }
+        fn visit_u128<E>(self, v: u128) -> ::core::result::Result<Self::Value, E> {
+            ::core::result::Result::Ok(__Content::U128(v))
+        }
+        fn visit_i128<E>(self, v: i128) -> ::core::result::Result<Self::Value, E> {
+            ::core::result::Result::Ok(__Content::I128(v))
+        }
+        fn visit_f64<E>(self, v: f64) -> ::core::result::Result<Self::Value, E> {
+            ::core::result::Result::Ok(__Content::F64(v))
+        }
+        fn visit_str<E>(self, v: &str) -> ::core::result::Result<Self::Value, E> {
+            ::core::result::Result::Ok(__Content::String(v.into()))
+        }
+        fn visit_borrowed_str<E>(
+            self,
+            v: &'de str,
+        ) -> ::core::result::Result<Self::Value, E> {
+            ::core::result::Result::Ok(__Content::Str(v))
+        }
+        fn visit_string<E>(
+            self,
+            v: __alloc::string::String,
+        ) -> ::core::result::Result<Self::Value, E> {
+            ::core::result::Result::Ok(__Content::String(v))
+        }
+        fn visit_bytes<E>(self, v: &[u8]) -> ::core::result::Result<Self::Value, E> {
+            ::core::result::Result::Ok(__Content::ByteBuf(v.into()))
+        }
+        fn visit_borrowed_bytes<E>(
+            self,
+            v: &'de [u8],
+        ) -> ::core::result::Result<Self::Value, E> {
+            ::core::result::Result::Ok(__Content::Bytes(v))
+        }
+        fn visit_byte_buf<E>(
+            self,
+            v: __alloc::vec::Vec<u8>,
+        ) -> ::core::result::Result<Self::Value, E> {
+            ::core::result::Result::Ok(__Content::ByteBuf(v))
+        }
+        fn visit_unit<E>(self) -> ::core::result::Result<Self::Value, E> {
+            ::core::result::Result::Ok(__Content::Unit)
+        }
+        fn visit_none<E>(self) -> ::core::result::Result<Self::Value, E> {
+            ::core::result::Result::Ok(__Content::None)
+        }
+        fn visit_some<D>(
+            self,
+            deserializer: D,
+        ) -> ::core::result::Result<Self::Value, D::Error>
+        where
+            D: ::serde::de::Deserializer<'de>,
+        {
+            let v = ::serde::de::Deserialize::deserialize(deserializer)?;
+            ::core::result::Result::Ok(__Content::Some(__alloc::boxed::Box::new(v)))
+        }
+        fn visit_newtype_struct<D>(
+            self,
+            deserializer: D,
+        ) -> ::core::result::Result<Self::Value, D::Error>
+        where
+            D: ::serde::de::Deserializer<'de>,
+        {
+            let v = ::serde::de::Deserialize::deserialize(deserializer)?;
+            ::core::result::Result::Ok(__Content::Newtype(__alloc::boxed::Box::new(v)))
+        }
+        fn visit_seq<A>(
+            self,
+            mut seq: A,
+        ) -> ::core::result::Result<Self::Value, A::Error>
+        where
+            A: ::serde::de::SeqAccess<'de>,
+        {
+            let mut v = __alloc::vec::Vec::new();
+            while let ::core::option::Option::Some(e) = seq.next_element()? {
+                v.push(e);
+            }
+            ::core::result::Result::Ok(__Content::Seq(v))
+        }
+        fn visit_map<A>(
+            self,
+            mut map: A,
+        ) -> ::core::result::Result<Self::Value, A::Error>
+        where
+            A: ::serde::de::MapAccess<'de>,
+        {
+            let mut v = __alloc::vec::Vec::new();
+            while let ::core::option::Option::Some(e) = map.next_entry()? {
+                v.push(e);
+            }
+            ::core::result::Result::Ok(__Content::Map(v))
+        }
+    }
+    struct __ContentRefDeserializer<'a, 'de: 'a, E> {
+        content: &'a __Content<'de>,
+        err: ::core::marker::PhantomData<E>,
+    }
+    impl<'a, 'de, E> __ContentRefDeserializer<'a, 'de, E> {
+        fn new(content: &'a __Content<'de>) -> Self {
+            Self {
+                content,
+                err: ::core::marker::PhantomData,
+            }
+        }
+    }
+    impl<'a, 'de, E> ::serde::de::IntoDeserializer<'de, E> for &'a __Content<'de>
+    where
+        E: ::serde::de::Error,
+    {
+        type Deserializer = __ContentRefDeserializer<'a, 'de, E>;
+        fn into_deserializer(self) -> Self::Deserializer {
+            __ContentRefDeserializer::new(self)
+        }
+    }
+    impl<'a, 'de, E> ::serde::de::Deserializer<'de>
+    for __ContentRefDeserializer<'a, 'de, E>
+    where
+        E: ::serde::de::Error,
+    {
+        type Error = E;
+        fn deserialize_any<V>(self, visitor: V) -> ::core::result::Result<V::Value, E>
+        where
+            V: ::serde::de::Visitor<'de>,
+        {
+            match *self.content {
+                __Content::Bool(v) => visitor.visit_bool(v),
+                __Content::U64(v) => visitor.visit_u64(v),
+                __Content::I64(v) => visitor.visit_i64(v),
+                __Content::U128(v) => visitor.visit_u128(v),
+                __Content::I128(v) => visitor.visit_i128(v),
+                __Content::F64(v) => visitor.visit_f64(v),
+                __Content::String(ref v) => visitor.visit_str(v),
+                __Content::Str(v) => visitor.visit_borrowed_str(v),
+                __Content::ByteBuf(ref v) => visitor.visit_bytes(v),
+                __Content::Bytes(v) => visitor.visit_borrowed_bytes(v),
+                __Content::None => visitor.visit_none(),
+                __Content::Some(ref v) => visitor.visit_some(Self::new(v)),
+                __Content::Unit => visitor.visit_unit(),
+                __Content::Newtype(ref v) => visitor.visit_newtype_struct(Self::new(v)),
+                __Content::Seq(ref v) => {
+                    let mut seq = ::serde::de::value::SeqDeserializer::new(v.iter());
+                    let value = visitor.visit_seq(&mut seq)?;
+                    seq.end()?;
+                    ::core::result::Result::Ok(value)
+                }
+                __Content::Map(ref v) => {
+                    let mut map = ::serde::de::value::MapDeserializer::new(
+                        v.iter().map(|e| (&e.0, &e.1)),
+                    );
+                    let value = visitor.visit_map(&mut map)?;
+                    map.end()?;
+                    ::core::result::Result::Ok(value)
+                }
+            }
+        }
+        fn deserialize_option<V>(self, visitor: V) -> ::core::result::Result<V::Value, E>
+        where
+            V: ::serde::de::Visitor<'de>,
+        {
+            match *self.content {
+                __Content::None | __Content::Unit => visitor.visit_none(),
+                __Content::Some(ref v) => visitor.visit_some(Self::new(v)),
+                _ => visitor.visit_some(self),
+            }
+        }
+        fn deserialize_newtype_struct<V>(
+            self,
+            _name: &'static str,
+            visitor: V,
+        ) -> ::core::result::Result<V::Value, E>
+        where
+            V: ::serde::de::Visitor<'de>,
+        {
+            match *self.content {
+                __Content::Newtype(ref v) => visitor.visit_newtype_struct(Self::new(v)),
+                _ => visitor.visit_newtype_struct(self),
+            }
+        }
+        fn deserialize_enum<V>(
+            self,
+            _name: &'static str,
+            _variants: &'static [&'static str],
+            visitor: V,
+        ) -> ::core::result::Result<V::Value, E>
+        where
+            V: ::serde::de::Visitor<'de>,
+        {
+            match *self.content {
+                __Content::Map(ref v) if v.len() == 1 => {
+                    visitor
+                        .visit_enum(
+                            ::serde::de::value::MapAccessDeserializer::new(
+                                ::serde::de::value::MapDeserializer::<
+                                    _,
+                                    E,
+                                >::new(v.iter().map(|e| (&e.0, &e.1))),
+                            ),
+                        )
+                }
+                __Content::String(ref v) => {
+                    visitor.visit_enum(::serde::de::value::StrDeserializer::<E>::new(v))
+                }
+                __Content::Str(v) => {
+                    visitor
+                        .visit_enum(
+                            ::serde::de::value::BorrowedStrDeserializer::<E>::new(v),
+                        )
+                }
+                _ => ::serde::de::Deserializer::deserialize_any(self, visitor),
+            }
+        }
+        #[inline]
+        fn deserialize_bool<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_i8<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_i16<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_i32<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_i64<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_i128<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_u8<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_u16<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_u32<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_u64<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_u128<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_f32<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_f64<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_char<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_str<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_string<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_bytes<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_byte_buf<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_unit<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_unit_struct<V>(
+            self,
+            name: &'static str,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            let _ = name;
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_seq<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_tuple<V>(
+            self,
+            len: usize,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            let _ = len;
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_tuple_struct<V>(
+            self,
+            name: &'static str,
+            len: usize,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            let _ = name;
+            let _ = len;
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_map<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_struct<V>(
+            self,
+            name: &'static str,
+            fields: &'static [&'static str],
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            let _ = name;
+            let _ = fields;
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_identifier<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+        #[inline]
+        fn deserialize_ignored_any<V>(
+            self,
+            visitor: V,
+        ) -> ::serde_core::__private::Result<
+            V::Value,
+            <Self as ::serde_core::de::Deserializer<'de>>::Error,
+        >
+        where
+            V: ::serde_core::de::Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+    }
+    impl<'__de, A, B> ::serde::de::Deserialize<'__de> for Enum<A, B>
+    where
+        A: ::serde::de::Deserialize<'__de>,
+        B: ::serde::de::Deserialize<'__de>,
+    {
+        fn deserialize<__D>(
+            __deserializer: __D,
+        ) -> ::core::result::Result<Self, __D::Error>
+        where
+            __D: ::serde::de::Deserializer<'__de>,
+        {
+            let __content: __Content<'__de> = ::serde::de::Deserialize::deserialize(
+                __deserializer,
+            )?;
+            let mut __errors = __alloc::string::String::new();
+            match <A as ::serde::de::Deserialize<
+                '__de,
+            >>::deserialize(__ContentRefDeserializer::<__D::Error>::new(&__content)) {
+                ::core::result::Result::Ok(x) => {
+                    return ::core::result::Result::Ok(Enum::A(x));
+                }
+                ::core::result::Result::Err(e) => {
+                    let _ = ::core::fmt::Write::write_fmt(
+                        &mut __errors,
+                        format_args!("\n    variant `{0}`: {1}", "A", e),
+                    );
+                }
+            }
+            match <B as ::serde::de::Deserialize<
+                '__de,
+            >>::deserialize(__ContentRefDeserializer::<__D::Error>::new(&__content)) {
+                ::core::result::Result::Ok(x) => {
+                    return ::core::result::Result::Ok(Enum::B(x));
+                }
+                ::core::result::Result::Err(e) => {
+                    let _ = ::core::fmt::Write::write_fmt(
+                        &mut __errors,
+                        format_args!("\n    variant `{0}`: {1}", "B", e),
+                    );
+                }
+            }
+            ::core::result::Result::Err(
+                <__D::Error as ::serde::de::Error>::custom(
+                    format_args!(
+                        "data did not match any variant of enum {0}:{1}",
+                        "Enum",
+                        __errors,
+                    ),
+                ),
+            )
+        }
+    }
+};
+fn main() {}
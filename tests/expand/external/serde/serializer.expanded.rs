@@ -0,0 +1,819 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+impl<A, B> ::serde::ser::Serializer for Enum<A, B>
+where
+    A: ::serde::ser::Serializer,
+    B: ::serde::ser::Serializer<
+        Ok = <A as ::serde::ser::Serializer>::Ok,
+        Error = <A as ::serde::ser::Serializer>::Error,
+    >,
+{
+    type Ok = <A as ::serde::ser::Serializer>::Ok;
+    type Error = <A as ::serde::ser::Serializer>::Error;
+    #[inline]
+    fn serialize_bool(self, v: bool) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::serialize_bool(x, v),
+            Enum::B(x) => ::serde::ser::Serializer::serialize_bool(x, v),
+        }
+    }
+    #[inline]
+    fn serialize_i8(self, v: i8) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::serialize_i8(x, v),
+            Enum::B(x) => ::serde::ser::Serializer::serialize_i8(x, v),
+        }
+    }
+    #[inline]
+    fn serialize_i16(self, v: i16) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::serialize_i16(x, v),
+            Enum::B(x) => ::serde::ser::Serializer::serialize_i16(x, v),
+        }
+    }
+    #[inline]
+    fn serialize_i32(self, v: i32) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::serialize_i32(x, v),
+            Enum::B(x) => ::serde::ser::Serializer::serialize_i32(x, v),
+        }
+    }
+    #[inline]
+    fn serialize_i64(self, v: i64) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::serialize_i64(x, v),
+            Enum::B(x) => ::serde::ser::Serializer::serialize_i64(x, v),
+        }
+    }
+    #[inline]
+    fn serialize_i128(self, v: i128) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::serialize_i128(x, v),
+            Enum::B(x) => ::serde::ser::Serializer::serialize_i128(x, v),
+        }
+    }
+    #[inline]
+    fn serialize_u8(self, v: u8) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::serialize_u8(x, v),
+            Enum::B(x) => ::serde::ser::Serializer::serialize_u8(x, v),
+        }
+    }
+    #[inline]
+    fn serialize_u16(self, v: u16) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::serialize_u16(x, v),
+            Enum::B(x) => ::serde::ser::Serializer::serialize_u16(x, v),
+        }
+    }
+    #[inline]
+    fn serialize_u32(self, v: u32) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::serialize_u32(x, v),
+            Enum::B(x) => ::serde::ser::Serializer::serialize_u32(x, v),
+        }
+    }
+    #[inline]
+    fn serialize_u64(self, v: u64) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::serialize_u64(x, v),
+            Enum::B(x) => ::serde::ser::Serializer::serialize_u64(x, v),
+        }
+    }
+    #[inline]
+    fn serialize_u128(self, v: u128) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::serialize_u128(x, v),
+            Enum::B(x) => ::serde::ser::Serializer::serialize_u128(x, v),
+        }
+    }
+    #[inline]
+    fn serialize_f32(self, v: f32) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::serialize_f32(x, v),
+            Enum::B(x) => ::serde::ser::Serializer::serialize_f32(x, v),
+        }
+    }
+    #[inline]
+    fn serialize_f64(self, v: f64) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::serialize_f64(x, v),
+            Enum::B(x) => ::serde::ser::Serializer::serialize_f64(x, v),
+        }
+    }
+    #[inline]
+    fn serialize_char(self, v: char) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::serialize_char(x, v),
+            Enum::B(x) => ::serde::ser::Serializer::serialize_char(x, v),
+        }
+    }
+    #[inline]
+    fn serialize_str(self, v: &str) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::serialize_str(x, v),
+            Enum::B(x) => ::serde::ser::Serializer::serialize_str(x, v),
+        }
+    }
+    #[inline]
+    fn serialize_bytes(self, v: &[u8]) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::serialize_bytes(x, v),
+            Enum::B(x) => ::serde::ser::Serializer::serialize_bytes(x, v),
+        }
+    }
+    #[inline]
+    fn serialize_none(self) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::serialize_none(x),
+            Enum::B(x) => ::serde::ser::Serializer::serialize_none(x),
+        }
+    }
+    #[inline]
+    fn serialize_some<__T>(
+        self,
+        value: &__T,
+    ) -> ::core::result::Result<Self::Ok, Self::Error>
+    where
+        __T: ?Sized + ::serde::ser::Serialize,
+    {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::serialize_some(x, value),
+            Enum::B(x) => ::serde::ser::Serializer::serialize_some(x, value),
+        }
+    }
+    #[inline]
+    fn serialize_unit(self) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::serialize_unit(x),
+            Enum::B(x) => ::serde::ser::Serializer::serialize_unit(x),
+        }
+    }
+    #[inline]
+    fn serialize_unit_struct(
+        self,
+        name: &'static str,
+    ) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::serialize_unit_struct(x, name),
+            Enum::B(x) => ::serde::ser::Serializer::serialize_unit_struct(x, name),
+        }
+    }
+    #[inline]
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            Enum::A(x) => {
+                ::serde::ser::Serializer::serialize_unit_variant(
+                    x,
+                    name,
+                    variant_index,
+                    variant,
+                )
+            }
+            Enum::B(x) => {
+                ::serde::ser::Serializer::serialize_unit_variant(
+                    x,
+                    name,
+                    variant_index,
+                    variant,
+                )
+            }
+        }
+    }
+    #[inline]
+    fn serialize_newtype_struct<__T>(
+        self,
+        name: &'static str,
+        value: &__T,
+    ) -> ::core::result::Result<Self::Ok, Self::Error>
+    where
+        __T: ?Sized + ::serde::ser::Serialize,
+    {
+        match self {
+            Enum::A(x) => {
+                ::serde::ser::Serializer::serialize_newtype_struct(x, name, value)
+            }
+            Enum::B(x) => {
+                ::serde::ser::Serializer::serialize_newtype_struct(x, name, value)
+            }
+        }
+    }
+    #[inline]
+    fn serialize_newtype_variant<__T>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &__T,
+    ) -> ::core::result::Result<Self::Ok, Self::Error>
+    where
+        __T: ?Sized + ::serde::ser::Serialize,
+    {
+        match self {
+            Enum::A(x) => {
+                ::serde::ser::Serializer::serialize_newtype_variant(
+                    x,
+                    name,
+                    variant_index,
+                    variant,
+                    value,
+                )
+            }
+            Enum::B(x) => {
+                ::serde::ser::Serializer::serialize_newtype_variant(
+                    x,
+                    name,
+                    variant_index,
+                    variant,
+                    value,
+                )
+            }
+        }
+    }
+    #[inline]
+    fn collect_str<__T>(
+        self,
+        value: &__T,
+    ) -> ::core::result::Result<Self::Ok, Self::Error>
+    where
+        __T: ?Sized + ::core::fmt::Display,
+    {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::collect_str(x, value),
+            Enum::B(x) => ::serde::ser::Serializer::collect_str(x, value),
+        }
+    }
+    #[inline]
+    fn is_human_readable(&self) -> bool {
+        match self {
+            Enum::A(x) => ::serde::ser::Serializer::is_human_readable(x),
+            Enum::B(x) => ::serde::ser::Serializer::is_human_readable(x),
+        }
+    }
+    type SerializeSeq = __EnumSerializeSeq<
+        <A as ::serde::ser::Serializer>::SerializeSeq,
+        <B as ::serde::ser::Serializer>::SerializeSeq,
+    >;
+    #[inline]
+    fn serialize_seq(
+        self,
+        len: ::core::option::Option<usize>,
+    ) -> ::core::result::Result<Self::SerializeSeq, Self::Error> {
+        match self {
+            Enum::A(x) => {
+                ::core::result::Result::map(
+                    ::serde::ser::Serializer::serialize_seq(x, len),
+                    __EnumSerializeSeq::A,
+                )
+            }
+            Enum::B(x) => {
+                ::core::result::Result::map(
+                    ::serde::ser::Serializer::serialize_seq(x, len),
+                    __EnumSerializeSeq::B,
+                )
+            }
+        }
+    }
+    type SerializeTuple = __EnumSerializeTuple<
+        <A as ::serde::ser::Serializer>::SerializeTuple,
+        <B as ::serde::ser::Serializer>::SerializeTuple,
+    >;
+    #[inline]
+    fn serialize_tuple(
+        self,
+        len: usize,
+    ) -> ::core::result::Result<Self::SerializeTuple, Self::Error> {
+        match self {
+            Enum::A(x) => {
+                ::core::result::Result::map(
+                    ::serde::ser::Serializer::serialize_tuple(x, len),
+                    __EnumSerializeTuple::A,
+                )
+            }
+            Enum::B(x) => {
+                ::core::result::Result::map(
+                    ::serde::ser::Serializer::serialize_tuple(x, len),
+                    __EnumSerializeTuple::B,
+                )
+            }
+        }
+    }
+    type SerializeTupleStruct = __EnumSerializeTupleStruct<
+        <A as ::serde::ser::Serializer>::SerializeTupleStruct,
+        <B as ::serde::ser::Serializer>::SerializeTupleStruct,
+    >;
+    #[inline]
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> ::core::result::Result<Self::SerializeTupleStruct, Self::Error> {
+        match self {
+            Enum::A(x) => {
+                ::core::result::Result::map(
+                    ::serde::ser::Serializer::serialize_tuple_struct(x, name, len),
+                    __EnumSerializeTupleStruct::A,
+                )
+            }
+            Enum::B(x) => {
+                ::core::result::Result::map(
+                    ::serde::ser::Serializer::serialize_tuple_struct(x, name, len),
+                    __EnumSerializeTupleStruct::B,
+                )
+            }
+        }
+    }
+    type SerializeTupleVariant = __EnumSerializeTupleVariant<
+        <A as ::serde::ser::Serializer>::SerializeTupleVariant,
+        <B as ::serde::ser::Serializer>::SerializeTupleVariant,
+    >;
+    #[inline]
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> ::core::result::Result<Self::SerializeTupleVariant, Self::Error> {
+        match self {
+            Enum::A(x) => {
+                ::core::result::Result::map(
+                    ::serde::ser::Serializer::serialize_tuple_variant(
+                        x,
+                        name,
+                        variant_index,
+                        variant,
+                        len,
+                    ),
+                    __EnumSerializeTupleVariant::A,
+                )
+            }
+            Enum::B(x) => {
+                ::core::result::Result::map(
+                    ::serde::ser::Serializer::serialize_tuple_variant(
+                        x,
+                        name,
+                        variant_index,
+                        variant,
+                        len,
+                    ),
+                    __EnumSerializeTupleVariant::B,
+                )
+            }
+        }
+    }
+    type SerializeMap = __EnumSerializeMap<
+        <A as ::serde::ser::Serializer>::SerializeMap,
+        <B as ::serde::ser::Serializer>::SerializeMap,
+    >;
+    #[inline]
+    fn serialize_map(
+        self,
+        len: ::core::option::Option<usize>,
+    ) -> ::core::result::Result<Self::SerializeMap, Self::Error> {
+        match self {
+            Enum::A(x) => {
+                ::core::result::Result::map(
+                    ::serde::ser::Serializer::serialize_map(x, len),
+                    __EnumSerializeMap::A,
+                )
+            }
+            Enum::B(x) => {
+                ::core::result::Result::map(
+                    ::serde::ser::Serializer::serialize_map(x, len),
+                    __EnumSerializeMap::B,
+                )
+            }
+        }
+    }
+    type SerializeStruct = __EnumSerializeStruct<
+        <A as ::serde::ser::Serializer>::SerializeStruct,
+        <B as ::serde::ser::Serializer>::SerializeStruct,
+    >;
+    #[inline]
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> ::core::result::Result<Self::SerializeStruct, Self::Error> {
+        match self {
+            Enum::A(x) => {
+                ::core::result::Result::map(
+                    ::serde::ser::Serializer::serialize_struct(x, name, len),
+                    __EnumSerializeStruct::A,
+                )
+            }
+            Enum::B(x) => {
+                ::core::result::Result::map(
+                    ::serde::ser::Serializer::serialize_struct(x, name, len),
+                    __EnumSerializeStruct::B,
+                )
+            }
+        }
+    }
+    type SerializeStructVariant = __EnumSerializeStructVariant<
+        <A as ::serde::ser::Serializer>::SerializeStructVariant,
+        <B as ::serde::ser::Serializer>::SerializeStructVariant,
+    >;
+    #[inline]
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> ::core::result::Result<Self::SerializeStructVariant, Self::Error> {
+        match self {
+            Enum::A(x) => {
+                ::core::result::Result::map(
+                    ::serde::ser::Serializer::serialize_struct_variant(
+                        x,
+                        name,
+                        variant_index,
+                        variant,
+                        len,
+                    ),
+                    __EnumSerializeStructVariant::A,
+                )
+            }
+            Enum::B(x) => {
+                ::core::result::Result::map(
+                    ::serde::ser::Serializer::serialize_struct_variant(
+                        x,
+                        name,
+                        variant_index,
+                        variant,
+                        len,
+                    ),
+                    __EnumSerializeStructVariant::B,
+                )
+            }
+        }
+    }
+}
+#[doc(hidden)]
+enum __EnumSerializeSeq<__T0, __T1> {
+    A(__T0),
+    B(__T1),
+}
+impl<__T0, __T1> ::serde::ser::SerializeSeq for __EnumSerializeSeq<__T0, __T1>
+where
+    __T0: ::serde::ser::SerializeSeq,
+    __T1: ::serde::ser::SerializeSeq<
+        Ok = <__T0 as ::serde::ser::SerializeSeq>::Ok,
+        Error = <__T0 as ::serde::ser::SerializeSeq>::Error,
+    >,
+{
+    type Ok = <__T0 as ::serde::ser::SerializeSeq>::Ok;
+    type Error = <__T0 as ::serde::ser::SerializeSeq>::Error;
+    #[inline]
+    fn serialize_element<__T>(
+        &mut self,
+        value: &__T,
+    ) -> ::core::result::Result<(), Self::Error>
+    where
+        __T: ?Sized + ::serde::ser::Serialize,
+    {
+        match self {
+            __EnumSerializeSeq::A(x) => {
+                ::serde::ser::SerializeSeq::serialize_element(x, value)
+            }
+            __EnumSerializeSeq::B(x) => {
+                ::serde::ser::SerializeSeq::serialize_element(x, value)
+            }
+        }
+    }
+    #[inline]
+    fn end(self) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            __EnumSerializeSeq::A(x) => ::serde::ser::SerializeSeq::end(x),
+            __EnumSerializeSeq::B(x) => ::serde::ser::SerializeSeq::end(x),
+        }
+    }
+}
+#[doc(hidden)]
+enum __EnumSerializeTuple<__T0, __T1> {
+    A(__T0),
+    B(__T1),
+}
+impl<__T0, __T1> ::serde::ser::SerializeTuple for __EnumSerializeTuple<__T0, __T1>
+where
+    __T0: ::serde::ser::SerializeTuple,
+    __T1: ::serde::ser::SerializeTuple<
+        Ok = <__T0 as ::serde::ser::SerializeTuple>::Ok,
+        Error = <__T0 as ::serde::ser::SerializeTuple>::Error,
+    >,
+{
+    type Ok = <__T0 as ::serde::ser::SerializeTuple>::Ok;
+    type Error = <__T0 as ::serde::ser::SerializeTuple>::Error;
+    #[inline]
+    fn serialize_element<__T>(
+        &mut self,
+        value: &__T,
+    ) -> ::core::result::Result<(), Self::Error>
+    where
+        __T: ?Sized + ::serde::ser::Serialize,
+    {
+        match self {
+            __EnumSerializeTuple::A(x) => {
+                ::serde::ser::SerializeTuple::serialize_element(x, value)
+            }
+            __EnumSerializeTuple::B(x) => {
+                ::serde::ser::SerializeTuple::serialize_element(x, value)
+            }
+        }
+    }
+    #[inline]
+    fn end(self) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            __EnumSerializeTuple::A(x) => ::serde::ser::SerializeTuple::end(x),
+            __EnumSerializeTuple::B(x) => ::serde::ser::SerializeTuple::end(x),
+        }
+    }
+}
+#[doc(hidden)]
+enum __EnumSerializeTupleStruct<__T0, __T1> {
+    A(__T0),
+    B(__T1),
+}
+impl<__T0, __T1> ::serde::ser::SerializeTupleStruct
+for __EnumSerializeTupleStruct<__T0, __T1>
+where
+    __T0: ::serde::ser::SerializeTupleStruct,
+    __T1: ::serde::ser::SerializeTupleStruct<
+        Ok = <__T0 as ::serde::ser::SerializeTupleStruct>::Ok,
+        Error = <__T0 as ::serde::ser::SerializeTupleStruct>::Error,
+    >,
+{
+    type Ok = <__T0 as ::serde::ser::SerializeTupleStruct>::Ok;
+    type Error = <__T0 as ::serde::ser::SerializeTupleStruct>::Error;
+    #[inline]
+    fn serialize_field<__T>(
+        &mut self,
+        value: &__T,
+    ) -> ::core::result::Result<(), Self::Error>
+    where
+        __T: ?Sized + ::serde::ser::Serialize,
+    {
+        match self {
+            __EnumSerializeTupleStruct::A(x) => {
+                ::serde::ser::SerializeTupleStruct::serialize_field(x, value)
+            }
+            __EnumSerializeTupleStruct::B(x) => {
+                ::serde::ser::SerializeTupleStruct::serialize_field(x, value)
+            }
+        }
+    }
+    #[inline]
+    fn end(self) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            __EnumSerializeTupleStruct::A(x) => {
+                ::serde::ser::SerializeTupleStruct::end(x)
+            }
+            __EnumSerializeTupleStruct::B(x) => {
+                ::serde::ser::SerializeTupleStruct::end(x)
+            }
+        }
+    }
+}
+#[doc(hidden)]
+enum __EnumSerializeTupleVariant<__T0, __T1> {
+    A(__T0),
+    B(__T1),
+}
+impl<__T0, __T1> ::serde::ser::SerializeTupleVariant
+for __EnumSerializeTupleVariant<__T0, __T1>
+where
+    __T0: ::serde::ser::SerializeTupleVariant,
+    __T1: ::serde::ser::SerializeTupleVariant<
+        Ok = <__T0 as ::serde::ser::SerializeTupleVariant>::Ok,
+        Error = <__T0 as ::serde::ser::SerializeTupleVariant>::Error,
+    >,
+{
+    type Ok = <__T0 as ::serde::ser::SerializeTupleVariant>::Ok;
+    type Error = <__T0 as ::serde::ser::SerializeTupleVariant>::Error;
+    #[inline]
+    fn serialize_field<__T>(
+        &mut self,
+        value: &__T,
+    ) -> ::core::result::Result<(), Self::Error>
+    where
+        __T: ?Sized + ::serde::ser::Serialize,
+    {
+        match self {
+            __EnumSerializeTupleVariant::A(x) => {
+                ::serde::ser::SerializeTupleVariant::serialize_field(x, value)
+            }
+            __EnumSerializeTupleVariant::B(x) => {
+                ::serde::ser::SerializeTupleVariant::serialize_field(x, value)
+            }
+        }
+    }
+    #[inline]
+    fn end(self) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            __EnumSerializeTupleVariant::A(x) => {
+                ::serde::ser::SerializeTupleVariant::end(x)
+            }
+            __EnumSerializeTupleVariant::B(x) => {
+                ::serde::ser::SerializeTupleVariant::end(x)
+            }
+        }
+    }
+}
+#[doc(hidden)]
+enum __EnumSerializeMap<__T0, __T1> {
+    A(__T0),
+    B(__T1),
+}
+impl<__T0, __T1> ::serde::ser::SerializeMap for __EnumSerializeMap<__T0, __T1>
+where
+    __T0: ::serde::ser::SerializeMap,
+    __T1: ::serde::ser::SerializeMap<
+        Ok = <__T0 as ::serde::ser::SerializeMap>::Ok,
+        Error = <__T0 as ::serde::ser::SerializeMap>::Error,
+    >,
+{
+    type Ok = <__T0 as ::serde::ser::SerializeMap>::Ok;
+    type Error = <__T0 as ::serde::ser::SerializeMap>::Error;
+    #[inline]
+    fn serialize_key<__T>(
+        &mut self,
+        key: &__T,
+    ) -> ::core::result::Result<(), Self::Error>
+    where
+        __T: ?Sized + ::serde::ser::Serialize,
+    {
+        match self {
+            __EnumSerializeMap::A(x) => ::serde::ser::SerializeMap::serialize_key(x, key),
+            __EnumSerializeMap::B(x) => ::serde::ser::SerializeMap::serialize_key(x, key),
+        }
+    }
+    #[inline]
+    fn serialize_value<__T>(
+        &mut self,
+        value: &__T,
+    ) -> ::core::result::Result<(), Self::Error>
+    where
+        __T: ?Sized + ::serde::ser::Serialize,
+    {
+        match self {
+            __EnumSerializeMap::A(x) => {
+                ::serde::ser::SerializeMap::serialize_value(x, value)
+            }
+            __EnumSerializeMap::B(x) => {
+                ::serde::ser::SerializeMap::serialize_value(x, value)
+            }
+        }
+    }
+    #[inline]
+    fn serialize_entry<__K, __V>(
+        &mut self,
+        key: &__K,
+        value: &__V,
+    ) -> ::core::result::Result<(), Self::Error>
+    where
+        __K: ?Sized + ::serde::ser::Serialize,
+        __V: ?Sized + ::serde::ser::Serialize,
+    {
+        match self {
+            __EnumSerializeMap::A(x) => {
+                ::serde::ser::SerializeMap::serialize_entry(x, key, value)
+            }
+            __EnumSerializeMap::B(x) => {
+                ::serde::ser::SerializeMap::serialize_entry(x, key, value)
+            }
+        }
+    }
+    #[inline]
+    fn end(self) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            __EnumSerializeMap::A(x) => ::serde::ser::SerializeMap::end(x),
+            __EnumSerializeMap::B(x) => ::serde::ser::SerializeMap::end(x),
+        }
+    }
+}
+#[doc(hidden)]
+enum __EnumSerializeStruct<__T0, __T1> {
+    A(__T0),
+    B(__T1),
+}
+impl<__T0, __T1> ::serde::ser::SerializeStruct for __EnumSerializeStruct<__T0, __T1>
+where
+    __T0: ::serde::ser::SerializeStruct,
+    __T1: ::serde::ser::SerializeStruct<
+        Ok = <__T0 as ::serde::ser::SerializeStruct>::Ok,
+        Error = <__T0 as ::serde::ser::SerializeStruct>::Error,
+    >,
+{
+    type Ok = <__T0 as ::serde::ser::SerializeStruct>::Ok;
+    type Error = <__T0 as ::serde::ser::SerializeStruct>::Error;
+    #[inline]
+    fn serialize_field<__T>(
+        &mut self,
+        key: &'static str,
+        value: &__T,
+    ) -> ::core::result::Result<(), Self::Error>
+    where
+        __T: ?Sized + ::serde::ser::Serialize,
+    {
+        match self {
+            __EnumSerializeStruct::A(x) => {
+                ::serde::ser::SerializeStruct::serialize_field(x, key, value)
+            }
+            __EnumSerializeStruct::B(x) => {
+                ::serde::ser::SerializeStruct::serialize_field(x, key, value)
+            }
+        }
+    }
+    #[inline]
+    fn skip_field(
+        &mut self,
+        key: &'static str,
+    ) -> ::core::result::Result<(), Self::Error> {
+        match self {
+            __EnumSerializeStruct::A(x) => {
+                ::serde::ser::SerializeStruct::skip_field(x, key)
+            }
+            __EnumSerializeStruct::B(x) => {
+                ::serde::ser::SerializeStruct::skip_field(x, key)
+            }
+        }
+    }
+    #[inline]
+    fn end(self) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            __EnumSerializeStruct::A(x) => ::serde::ser::SerializeStruct::end(x),
+            __EnumSerializeStruct::B(x) => ::serde::ser::SerializeStruct::end(x),
+        }
+    }
+}
+#[doc(hidden)]
+enum __EnumSerializeStructVariant<__T0, __T1> {
+    A(__T0),
+    B(__T1),
+}
+impl<__T0, __T1> ::serde::ser::SerializeStructVariant
+for __EnumSerializeStructVariant<__T0, __T1>
+where
+    __T0: ::serde::ser::SerializeStructVariant,
+    __T1: ::serde::ser::SerializeStructVariant<
+        Ok = <__T0 as ::serde::ser::SerializeStructVariant>::Ok,
+        Error = <__T0 as ::serde::ser::SerializeStructVariant>::Error,
+    >,
+{
+    type Ok = <__T0 as ::serde::ser::SerializeStructVariant>::Ok;
+    type Error = <__T0 as ::serde::ser::SerializeStructVariant>::Error;
+    #[inline]
+    fn serialize_field<__T>(
+        &mut self,
+        key: &'static str,
+        value: &__T,
+    ) -> ::core::result::Result<(), Self::Error>
+    where
+        __T: ?Sized + ::serde::ser::Serialize,
+    {
+        match self {
+            __EnumSerializeStructVariant::A(x) => {
+                ::serde::ser::SerializeStructVariant::serialize_field(x, key, value)
+            }
+            __EnumSerializeStructVariant::B(x) => {
+                ::serde::ser::SerializeStructVariant::serialize_field(x, key, value)
+            }
+        }
+    }
+    #[inline]
+    fn skip_field(
+        &mut self,
+        key: &'static str,
+    ) -> ::core::result::Result<(), Self::Error> {
+        match self {
+            __EnumSerializeStructVariant::A(x) => {
+                ::serde::ser::SerializeStructVariant::skip_field(x, key)
+            }
+            __EnumSerializeStructVariant::B(x) => {
+                ::serde::ser::SerializeStructVariant::skip_field(x, key)
+            }
+        }
+    }
+    #[inline]
+    fn end(self) -> ::core::result::Result<Self::Ok, Self::Error> {
+        match self {
+            __EnumSerializeStructVariant::A(x) => {
+                ::serde::ser::SerializeStructVariant::end(x)
+            }
+            __EnumSerializeStructVariant::B(x) => {
+                ::serde::ser::SerializeStructVariant::end(x)
+            }
+        }
+    }
+}
+fn main() {}
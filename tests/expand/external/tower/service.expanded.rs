@@ -0,0 +1,68 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+impl<A, B, __Request> ::tower::Service<__Request> for Enum<A, B>
+where
+    A: ::tower::Service<__Request>,
+    B: ::tower::Service<
+        __Request,
+        Response = <A as ::tower::Service<__Request>>::Response,
+        Error = <A as ::tower::Service<__Request>>::Error,
+    >,
+{
+    type Response = <A as ::tower::Service<__Request>>::Response;
+    type Error = <A as ::tower::Service<__Request>>::Error;
+    type Future = __EnumFuture<
+        <A as ::tower::Service<__Request>>::Future,
+        <B as ::tower::Service<__Request>>::Future,
+    >;
+    #[inline]
+    fn poll_ready(
+        &mut self,
+        cx: &mut ::core::task::Context<'_>,
+    ) -> ::core::task::Poll<::core::result::Result<(), Self::Error>> {
+        match self {
+            Enum::A(x) => ::tower::Service::poll_ready(x, cx),
+            Enum::B(x) => ::tower::Service::poll_ready(x, cx),
+        }
+    }
+    #[inline]
+    fn call(&mut self, req: __Request) -> Self::Future {
+        match self {
+            Enum::A(x) => __EnumFuture::A(::tower::Service::call(x, req)),
+            Enum::B(x) => __EnumFuture::B(::tower::Service::call(x, req)),
+        }
+    }
+}
+#[doc(hidden)]
+enum __EnumFuture<__T0, __T1> {
+    A(__T0),
+    B(__T1),
+}
+#[allow(unsafe_code)]
+impl<__T0, __T1> ::core::future::Future for __EnumFuture<__T0, __T1>
+where
+    __T0: ::core::future::Future,
+    __T1: ::core::future::Future<Output = <__T0 as ::core::future::Future>::Output>,
+{
+    type Output = <__T0 as ::core::future::Future>::Output;
+    #[inline]
+    fn poll(
+        self: ::core::pin::Pin<&mut Self>,
+        cx: &mut ::core::task::Context<'_>,
+    ) -> ::core::task::Poll<Self::Output> {
+        unsafe {
+            match self.get_unchecked_mut() {
+                __EnumFuture::A(x) => {
+                    ::core::future::Future::poll(::core::pin::Pin::new_unchecked(x), cx)
+                }
+                __EnumFuture::B(x) => {
+                    ::core::future::Future::poll(::core::pin::Pin::new_unchecked(x), cx)
+                }
+            }
+        }
+    }
+}
+fn main() {}
@@ -0,0 +1,153 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+#[allow(unsafe_code)]
+impl<A, B> ::tracing_core::Subscriber for Enum<A, B>
+where
+    A: ::tracing_core::Subscriber,
+    B: ::tracing_core::Subscriber,
+{
+    #[inline]
+    fn on_register_dispatch(&self, subscriber: &::tracing_core::Dispatch) {
+        match self {
+            Enum::A(x) => ::tracing_core::Subscriber::on_register_dispatch(x, subscriber),
+            Enum::B(x) => ::tracing_core::Subscriber::on_register_dispatch(x, subscriber),
+        }
+    }
+    #[inline]
+    fn register_callsite(
+        &self,
+        metadata: &'static ::tracing_core::Metadata<'static>,
+    ) -> ::tracing_core::subscriber::Interest {
+        match self {
+            Enum::A(x) => ::tracing_core::Subscriber::register_callsite(x, metadata),
+            Enum::B(x) => ::tracing_core::Subscriber::register_callsite(x, metadata),
+        }
+    }
+    #[inline]
+    fn enabled(&self, metadata: &::tracing_core::Metadata<'_>) -> bool {
+        match self {
+            Enum::A(x) => ::tracing_core::Subscriber::enabled(x, metadata),
+            Enum::B(x) => ::tracing_core::Subscriber::enabled(x, metadata),
+        }
+    }
+    #[inline]
+    fn max_level_hint(&self) -> ::core::option::Option<::tracing_core::LevelFilter> {
+        match self {
+            Enum::A(x) => ::tracing_core::Subscriber::max_level_hint(x),
+            Enum::B(x) => ::tracing_core::Subscriber::max_level_hint(x),
+        }
+    }
+    #[inline]
+    fn new_span(
+        &self,
+        span: &::tracing_core::span::Attributes<'_>,
+    ) -> ::tracing_core::span::Id {
+        match self {
+            Enum::A(x) => ::tracing_core::Subscriber::new_span(x, span),
+            Enum::B(x) => ::tracing_core::Subscriber::new_span(x, span),
+        }
+    }
+    #[inline]
+    fn record(
+        &self,
+        span: &::tracing_core::span::Id,
+        values: &::tracing_core::span::Record<'_>,
+    ) {
+        match self {
+            Enum::A(x) => ::tracing_core::Subscriber::record(x, span, values),
+            Enum::B(x) => ::tracing_core::Subscriber::record(x, span, values),
+        }
+    }
+    #[inline]
+    fn record_follows_from(
+        &self,
+        span: &::tracing_core::span::Id,
+        follows: &::tracing_core::span::Id,
+    ) {
+        match self {
+            Enum::A(x) => {
+                ::tracing_core::Subscriber::record_follows_from(x, span, follows)
+            }
+            Enum::B(x) => {
+                ::tracing_core::Subscriber::record_follows_from(x, span, follows)
+            }
+        }
+    }
+    #[inline]
+    fn event_enabled(&self, event: &::tracing_core::Event<'_>) -> bool {
+        match self {
+            Enum::A(x) => ::tracing_core::Subscriber::event_enabled(x, event),
+            Enum::B(x) => ::tracing_core::Subscriber::event_enabled(x, event),
+        }
+    }
+    #[inline]
+    fn event(&self, event: &::tracing_core::Event<'_>) {
+        match self {
+            Enum::A(x) => ::tracing_core::Subscriber::event(x, event),
+            Enum::B(x) => ::tracing_core::Subscriber::event(x, event),
+        }
+    }
+    #[inline]
+    fn enter(&self, span: &::tracing_core::span::Id) {
+        match self {
+            Enum::A(x) => ::tracing_core::Subscriber::enter(x, span),
+            Enum::B(x) => ::tracing_core::Subscriber::enter(x, span),
+        }
+    }
+    #[inline]
+    fn exit(&self, span: &::tracing_core::span::Id) {
+        match self {
+            Enum::A(x) => ::tracing_core::Subscriber::exit(x, span),
+            Enum::B(x) => ::tracing_core::Subscriber::exit(x, span),
+        }
+    }
+    #[inline]
+    fn clone_span(&self, id: &::tracing_core::span::Id) -> ::tracing_core::span::Id {
+        match self {
+            Enum::A(x) => ::tracing_core::Subscriber::clone_span(x, id),
+            Enum::B(x) => ::tracing_core::Subscriber::clone_span(x, id),
+        }
+    }
+    #[inline]
+    #[allow(deprecated)]
+    fn drop_span(&self, id: ::tracing_core::span::Id) {
+        match self {
+            Enum::A(x) => ::tracing_core::Subscriber::drop_span(x, id),
+            Enum::B(x) => ::tracing_core::Subscriber::drop_span(x, id),
+        }
+    }
+    #[inline]
+    fn try_close(&self, id: ::tracing_core::span::Id) -> bool {
+        match self {
+            Enum::A(x) => ::tracing_core::Subscriber::try_close(x, id),
+            Enum::B(x) => ::tracing_core::Subscriber::try_close(x, id),
+        }
+    }
+    #[inline]
+    fn current_span(&self) -> ::tracing_core::span::Current {
+        match self {
+            Enum::A(x) => ::tracing_core::Subscriber::current_span(x),
+            Enum::B(x) => ::tracing_core::Subscriber::current_span(x),
+        }
+    }
+    #[inline]
+    #[allow(unused_unsafe)]
+    unsafe fn downcast_raw(
+        &self,
+        id: ::core::any::TypeId,
+    ) -> ::core::option::Option<*const ()> {
+        if id == ::core::any::TypeId::of::<Self>() {
+            return ::core::option::Option::Some(self as *const Self as *const ());
+        }
+        unsafe {
+            match self {
+                Enum::A(x) => ::tracing_core::Subscriber::downcast_raw(x, id),
+                Enum::B(x) => ::tracing_core::Subscriber::downcast_raw(x, id),
+            }
+        }
+    }
+}
+fn main() {}
@@ -0,0 +1,183 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+#[allow(unsafe_code)]
+impl<A, B, __S> ::tracing_subscriber::Layer<__S> for Enum<A, B>
+where
+    A: ::tracing_subscriber::Layer<__S>,
+    B: ::tracing_subscriber::Layer<__S>,
+    __S: ::tracing_core::Subscriber,
+{
+    #[inline]
+    fn on_register_dispatch(&self, subscriber: &::tracing_core::Dispatch) {
+        match self {
+            Enum::A(x) => {
+                ::tracing_subscriber::Layer::on_register_dispatch(x, subscriber)
+            }
+            Enum::B(x) => {
+                ::tracing_subscriber::Layer::on_register_dispatch(x, subscriber)
+            }
+        }
+    }
+    #[inline]
+    fn on_layer(&mut self, subscriber: &mut __S) {
+        match self {
+            Enum::A(x) => ::tracing_subscriber::Layer::on_layer(x, subscriber),
+            Enum::B(x) => ::tracing_subscriber::Layer::on_layer(x, subscriber),
+        }
+    }
+    #[inline]
+    fn register_callsite(
+        &self,
+        metadata: &'static ::tracing_core::Metadata<'static>,
+    ) -> ::tracing_core::subscriber::Interest {
+        match self {
+            Enum::A(x) => ::tracing_subscriber::Layer::register_callsite(x, metadata),
+            Enum::B(x) => ::tracing_subscriber::Layer::register_callsite(x, metadata),
+        }
+    }
+    #[inline]
+    fn enabled(
+        &self,
+        metadata: &::tracing_core::Metadata<'_>,
+        ctx: ::tracing_subscriber::layer::Context<'_, __S>,
+    ) -> bool {
+        match self {
+            Enum::A(x) => ::tracing_subscriber::Layer::enabled(x, metadata, ctx),
+            Enum::B(x) => ::tracing_subscriber::Layer::enabled(x, metadata, ctx),
+        }
+    }
+    #[inline]
+    fn on_new_span(
+        &self,
+        attrs: &::tracing_core::span::Attributes<'_>,
+        id: &::tracing_core::span::Id,
+        ctx: ::tracing_subscriber::layer::Context<'_, __S>,
+    ) {
+        match self {
+            Enum::A(x) => ::tracing_subscriber::Layer::on_new_span(x, attrs, id, ctx),
+            Enum::B(x) => ::tracing_subscriber::Layer::on_new_span(x, attrs, id, ctx),
+        }
+    }
+    #[inline]
+    fn max_level_hint(&self) -> ::core::option::Option<::tracing_core::LevelFilter> {
+        match self {
+            Enum::A(x) => ::tracing_subscriber::Layer::max_level_hint(x),
+            Enum::B(x) => ::tracing_subscriber::Layer::max_level_hint(x),
+        }
+    }
+    #[inline]
+    fn on_record(
+        &self,
+        span: &::tracing_core::span::Id,
+        values: &::tracing_core::span::Record<'_>,
+        ctx: ::tracing_subscriber::layer::Context<'_, __S>,
+    ) {
+        match self {
+            Enum::A(x) => ::tracing_subscriber::Layer::on_record(x, span, values, ctx),
+            Enum::B(x) => ::tracing_subscriber::Layer::on_record(x, span, values, ctx),
+        }
+    }
+    #[inline]
+    fn on_follows_from(
+        &self,
+        span: &::tracing_core::span::Id,
+        follows: &::tracing_core::span::Id,
+        ctx: ::tracing_subscriber::layer::Context<'_, __S>,
+    ) {
+        match self {
+            Enum::A(x) => {
+                ::tracing_subscriber::Layer::on_follows_from(x, span, follows, ctx)
+            }
+            Enum::B(x) => {
+                ::tracing_subscriber::Layer::on_follows_from(x, span, follows, ctx)
+            }
+        }
+    }
+    #[inline]
+    fn event_enabled(
+        &self,
+        event: &::tracing_core::Event<'_>,
+        ctx: ::tracing_subscriber::layer::Context<'_, __S>,
+    ) -> bool {
+        match self {
+            Enum::A(x) => ::tracing_subscriber::Layer::event_enabled(x, event, ctx),
+            Enum::B(x) => ::tracing_subscriber::Layer::event_enabled(x, event, ctx),
+        }
+    }
+    #[inline]
+    fn on_event(
+        &self,
+        event: &::tracing_core::Event<'_>,
+        ctx: ::tracing_subscriber::layer::Context<'_, __S>,
+    ) {
+        match self {
+            Enum::A(x) => ::tracing_subscriber::Layer::on_event(x, event, ctx),
+            Enum::B(x) => ::tracing_subscriber::Layer::on_event(x, event, ctx),
+        }
+    }
+    #[inline]
+    fn on_enter(
+        &self,
+        id: &::tracing_core::span::Id,
+        ctx: ::tracing_subscriber::layer::Context<'_, __S>,
+    ) {
+        match self {
+            Enum::A(x) => ::tracing_subscriber::Layer::on_enter(x, id, ctx),
+            Enum::B(x) => ::tracing_subscriber::Layer::on_enter(x, id, ctx),
+        }
+    }
+    #[inline]
+    fn on_exit(
+        &self,
+        id: &::tracing_core::span::Id,
+        ctx: ::tracing_subscriber::layer::Context<'_, __S>,
+    ) {
+        match self {
+            Enum::A(x) => ::tracing_subscriber::Layer::on_exit(x, id, ctx),
+            Enum::B(x) => ::tracing_subscriber::Layer::on_exit(x, id, ctx),
+        }
+    }
+    #[inline]
+    fn on_close(
+        &self,
+        id: ::tracing_core::span::Id,
+        ctx: ::tracing_subscriber::layer::Context<'_, __S>,
+    ) {
+        match self {
+            Enum::A(x) => ::tracing_subscriber::Layer::on_close(x, id, ctx),
+            Enum::B(x) => ::tracing_subscriber::Layer::on_close(x, id, ctx),
+        }
+    }
+    #[inline]
+    fn on_id_change(
+        &self,
+        old: &::tracing_core::span::Id,
+        new: &::tracing_core::span::Id,
+        ctx: ::tracing_subscriber::layer::Context<'_, __S>,
+    ) {
+        match self {
+            Enum::A(x) => ::tracing_subscriber::Layer::on_id_change(x, old, new, ctx),
+            Enum::B(x) => ::tracing_subscriber::Layer::on_id_change(x, old, new, ctx),
+        }
+    }
+    #[inline]
+    #[allow(unused_unsafe)]
+    unsafe fn downcast_raw(
+        &self,
+        id: ::core::any::TypeId,
+    ) -> ::core::option::Option<*const ()> {
+        if id == ::core::any::TypeId::of::<Self>() {
+            return ::core::option::Option::Some(self as *const Self as *const ());
+        }
+        unsafe {
+            match self {
+                Enum::A(x) => ::tracing_subscriber::Layer::<__S>::downcast_raw(x, id),
+                Enum::B(x) => ::tracing_subscriber::Layer::<__S>::downcast_raw(x, id),
+            }
+        }
+    }
+}
+fn main() {}
@@ -0,0 +1,9 @@
+use auto_enums::enum_derive;
+
+#[enum_derive(tracing_subscriber::Layer)]
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+impl<A, B> ::core::borrow::Borrow<str> for Enum<A, B>
+where
+    A: ::core::borrow::Borrow<str>,
+    B: ::core::borrow::Borrow<str>,
+{
+    #[inline]
+    fn borrow(&self) -> &str {
+        match self {
+            Enum::A(x) => ::core::borrow::Borrow::<str>::borrow(x),
+            Enum::B(x) => ::core::borrow::Borrow::<str>::borrow(x),
+        }
+    }
+}
+impl<A, B> ::core::borrow::BorrowMut<str> for Enum<A, B>
+where
+    A: ::core::borrow::BorrowMut<str>,
+    B: ::core::borrow::BorrowMut<str>,
+{
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut str {
+        match self {
+            Enum::A(x) => ::core::borrow::BorrowMut::<str>::borrow_mut(x),
+            Enum::B(x) => ::core::borrow::BorrowMut::<str>::borrow_mut(x),
+        }
+    }
+}
+fn main() {}
@@ -0,0 +1,62 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+impl<A, B> ::core::hash::Hash for Enum<A, B>
+where
+    str: ::core::hash::Hash,
+    A: ::core::borrow::Borrow<str>,
+    B: ::core::borrow::Borrow<str>,
+{
+    #[inline]
+    fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+        match self {
+            Enum::A(x) => {
+                ::core::hash::Hash::hash(::core::borrow::Borrow::<str>::borrow(x), state)
+            }
+            Enum::B(x) => {
+                ::core::hash::Hash::hash(::core::borrow::Borrow::<str>::borrow(x), state)
+            }
+        }
+    }
+}
+impl<A, B> ::core::cmp::PartialEq for Enum<A, B>
+where
+    str: ::core::cmp::PartialEq,
+    A: ::core::borrow::Borrow<str>,
+    B: ::core::borrow::Borrow<str>,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        let x: &str = match self {
+            Enum::A(x) => ::core::borrow::Borrow::<str>::borrow(x),
+            Enum::B(x) => ::core::borrow::Borrow::<str>::borrow(x),
+        };
+        let y: &str = match other {
+            Enum::A(x) => ::core::borrow::Borrow::<str>::borrow(x),
+            Enum::B(x) => ::core::borrow::Borrow::<str>::borrow(x),
+        };
+        ::core::cmp::PartialEq::eq(x, y)
+    }
+}
+impl<A, B> ::core::cmp::Eq for Enum<A, B>
+where
+    str: ::core::cmp::Eq,
+    A: ::core::borrow::Borrow<str>,
+    B: ::core::borrow::Borrow<str>,
+{}
+impl<A, B> ::core::borrow::Borrow<str> for Enum<A, B>
+where
+    A: ::core::borrow::Borrow<str>,
+    B: ::core::borrow::Borrow<str>,
+{
+    #[inline]
+    fn borrow(&self) -> &str {
+        match self {
+            Enum::A(x) => ::core::borrow::Borrow::<str>::borrow(x),
+            Enum::B(x) => ::core::borrow::Borrow::<str>::borrow(x),
+        }
+    }
+}
+fn main() {}
@@ -0,0 +1,20 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+impl<A, B> ::core::cmp::PartialEq for Enum<A, B>
+where
+    A: ::core::cmp::PartialEq,
+    B: ::core::cmp::PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Enum::A(x), Enum::A(y)) => ::core::cmp::PartialEq::eq(x, y),
+            (Enum::B(x), Enum::B(y)) => ::core::cmp::PartialEq::eq(x, y),
+            _ => false,
+        }
+    }
+}
+fn main() {}
@@ -0,0 +1,32 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+impl<A, B> ::core::convert::AsRef<str> for Enum<A, B>
+where
+    A: ::core::convert::AsRef<str>,
+    B: ::core::convert::AsRef<str>,
+{
+    #[inline]
+    fn as_ref(&self) -> &str {
+        match self {
+            Enum::A(x) => ::core::convert::AsRef::<str>::as_ref(x),
+            Enum::B(x) => ::core::convert::AsRef::<str>::as_ref(x),
+        }
+    }
+}
+impl<A, B> ::core::convert::AsMut<str> for Enum<A, B>
+where
+    A: ::core::convert::AsMut<str>,
+    B: ::core::convert::AsMut<str>,
+{
+    #[inline]
+    fn as_mut(&mut self) -> &mut str {
+        match self {
+            Enum::A(x) => ::core::convert::AsMut::<str>::as_mut(x),
+            Enum::B(x) => ::core::convert::AsMut::<str>::as_mut(x),
+        }
+    }
+}
+fn main() {}
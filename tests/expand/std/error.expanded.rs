@@ -29,24 +29,24 @@ where
         }
     }
 }
-impl<A, B> ::std::error::Error for Enum<A, B>
+impl<A, B> ::core::error::Error for Enum<A, B>
 where
-    A: ::std::error::Error,
-    B: ::std::error::Error,
+    A: ::core::error::Error,
+    B: ::core::error::Error,
     A: 'static,
     B: 'static,
 {
     #[allow(deprecated)]
     fn description(&self) -> &str {
         match self {
-            Enum::A(x) => ::std::error::Error::description(x),
-            Enum::B(x) => ::std::error::Error::description(x),
+            Enum::A(x) => ::core::error::Error::description(x),
+            Enum::B(x) => ::core::error::Error::description(x),
         }
     }
-    fn source(&self) -> ::std::option::Option<&(dyn ::std::error::Error + 'static)> {
+    fn source(&self) -> ::core::option::Option<&(dyn ::core::error::Error + 'static)> {
         match self {
-            Enum::A(x) => ::std::option::Option::Some(x),
-            Enum::B(x) => ::std::option::Option::Some(x),
+            Enum::A(x) => ::core::option::Option::Some(x),
+            Enum::B(x) => ::core::option::Option::Some(x),
         }
     }
 }
@@ -0,0 +1,19 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+impl<A, B> ::std::os::unix::io::AsFd for Enum<A, B>
+where
+    A: ::std::os::unix::io::AsFd,
+    B: ::std::os::unix::io::AsFd,
+{
+    #[inline]
+    fn as_fd(&self) -> ::std::os::unix::io::BorrowedFd<'_> {
+        match self {
+            Enum::A(x) => ::std::os::unix::io::AsFd::as_fd(x),
+            Enum::B(x) => ::std::os::unix::io::AsFd::as_fd(x),
+        }
+    }
+}
+fn main() {}
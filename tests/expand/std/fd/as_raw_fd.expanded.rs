@@ -0,0 +1,19 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+impl<A, B> ::std::os::unix::io::AsRawFd for Enum<A, B>
+where
+    A: ::std::os::unix::io::AsRawFd,
+    B: ::std::os::unix::io::AsRawFd,
+{
+    #[inline]
+    fn as_raw_fd(&self) -> ::std::os::unix::io::RawFd {
+        match self {
+            Enum::A(x) => ::std::os::unix::io::AsRawFd::as_raw_fd(x),
+            Enum::B(x) => ::std::os::unix::io::AsRawFd::as_raw_fd(x),
+        }
+    }
+}
+fn main() {}
@@ -0,0 +1,19 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+impl<A, B> ::std::os::unix::io::IntoRawFd for Enum<A, B>
+where
+    A: ::std::os::unix::io::IntoRawFd,
+    B: ::std::os::unix::io::IntoRawFd,
+{
+    #[inline]
+    fn into_raw_fd(self) -> ::std::os::unix::io::RawFd {
+        match self {
+            Enum::A(x) => ::std::os::unix::io::IntoRawFd::into_raw_fd(x),
+            Enum::B(x) => ::std::os::unix::io::IntoRawFd::into_raw_fd(x),
+        }
+    }
+}
+fn main() {}
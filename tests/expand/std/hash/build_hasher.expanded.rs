@@ -0,0 +1,132 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+impl<A, B> ::core::hash::BuildHasher for Enum<A, B>
+where
+    A: ::core::hash::BuildHasher,
+    B: ::core::hash::BuildHasher,
+{
+    type Hasher = __EnumHasher<
+        <A as ::core::hash::BuildHasher>::Hasher,
+        <B as ::core::hash::BuildHasher>::Hasher,
+    >;
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        match self {
+            Enum::A(x) => __EnumHasher::A(::core::hash::BuildHasher::build_hasher(x)),
+            Enum::B(x) => __EnumHasher::B(::core::hash::BuildHasher::build_hasher(x)),
+        }
+    }
+}
+#[doc(hidden)]
+enum __EnumHasher<__T0, __T1> {
+    A(__T0),
+    B(__T1),
+}
+impl<__T0, __T1> ::core::hash::Hasher for __EnumHasher<__T0, __T1>
+where
+    __T0: ::core::hash::Hasher,
+    __T1: ::core::hash::Hasher,
+{
+    #[inline]
+    fn finish(&self) -> u64 {
+        match self {
+            __EnumHasher::A(x) => ::core::hash::Hasher::finish(x),
+            __EnumHasher::B(x) => ::core::hash::Hasher::finish(x),
+        }
+    }
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        match self {
+            __EnumHasher::A(x) => ::core::hash::Hasher::write(x, bytes),
+            __EnumHasher::B(x) => ::core::hash::Hasher::write(x, bytes),
+        }
+    }
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        match self {
+            __EnumHasher::A(x) => ::core::hash::Hasher::write_u8(x, i),
+            __EnumHasher::B(x) => ::core::hash::Hasher::write_u8(x, i),
+        }
+    }
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        match self {
+            __EnumHasher::A(x) => ::core::hash::Hasher::write_u16(x, i),
+            __EnumHasher::B(x) => ::core::hash::Hasher::write_u16(x, i),
+        }
+    }
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        match self {
+            __EnumHasher::A(x) => ::core::hash::Hasher::write_u32(x, i),
+            __EnumHasher::B(x) => ::core::hash::Hasher::write_u32(x, i),
+        }
+    }
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        match self {
+            __EnumHasher::A(x) => ::core::hash::Hasher::write_u64(x, i),
+            __EnumHasher::B(x) => ::core::hash::Hasher::write_u64(x, i),
+        }
+    }
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        match self {
+            __EnumHasher::A(x) => ::core::hash::Hasher::write_u128(x, i),
+            __EnumHasher::B(x) => ::core::hash::Hasher::write_u128(x, i),
+        }
+    }
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        match self {
+            __EnumHasher::A(x) => ::core::hash::Hasher::write_usize(x, i),
+            __EnumHasher::B(x) => ::core::hash::Hasher::write_usize(x, i),
+        }
+    }
+    #[inline]
+    fn write_i8(&mut self, i: i8) {
+        match self {
+            __EnumHasher::A(x) => ::core::hash::Hasher::write_i8(x, i),
+            __EnumHasher::B(x) => ::core::hash::Hasher::write_i8(x, i),
+        }
+    }
+    #[inline]
+    fn write_i16(&mut self, i: i16) {
+        match self {
+            __EnumHasher::A(x) => ::core::hash::Hasher::write_i16(x, i),
+            __EnumHasher::B(x) => ::core::hash::Hasher::write_i16(x, i),
+        }
+    }
+    #[inline]
+    fn write_i32(&mut self, i: i32) {
+        match self {
+            __EnumHasher::A(x) => ::core::hash::Hasher::write_i32(x, i),
+            __EnumHasher::B(x) => ::core::hash::Hasher::write_i32(x, i),
+        }
+    }
+    #[inline]
+    fn write_i64(&mut self, i: i64) {
+        match self {
+            __EnumHasher::A(x) => ::core::hash::Hasher::write_i64(x, i),
+            __EnumHasher::B(x) => ::core::hash::Hasher::write_i64(x, i),
+        }
+    }
+    #[inline]
+    fn write_i128(&mut self, i: i128) {
+        match self {
+            __EnumHasher::A(x) => ::core::hash::Hasher::write_i128(x, i),
+            __EnumHasher::B(x) => ::core::hash::Hasher::write_i128(x, i),
+        }
+    }
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        match self {
+            __EnumHasher::A(x) => ::core::hash::Hasher::write_isize(x, i),
+            __EnumHasher::B(x) => ::core::hash::Hasher::write_isize(x, i),
+        }
+    }
+}
+fn main() {}
@@ -0,0 +1,110 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+impl<A, B> ::core::hash::Hasher for Enum<A, B>
+where
+    A: ::core::hash::Hasher,
+    B: ::core::hash::Hasher,
+{
+    #[inline]
+    fn finish(&self) -> u64 {
+        match self {
+            Enum::A(x) => ::core::hash::Hasher::finish(x),
+            Enum::B(x) => ::core::hash::Hasher::finish(x),
+        }
+    }
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        match self {
+            Enum::A(x) => ::core::hash::Hasher::write(x, bytes),
+            Enum::B(x) => ::core::hash::Hasher::write(x, bytes),
+        }
+    }
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        match self {
+            Enum::A(x) => ::core::hash::Hasher::write_u8(x, i),
+            Enum::B(x) => ::core::hash::Hasher::write_u8(x, i),
+        }
+    }
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        match self {
+            Enum::A(x) => ::core::hash::Hasher::write_u16(x, i),
+            Enum::B(x) => ::core::hash::Hasher::write_u16(x, i),
+        }
+    }
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        match self {
+            Enum::A(x) => ::core::hash::Hasher::write_u32(x, i),
+            Enum::B(x) => ::core::hash::Hasher::write_u32(x, i),
+        }
+    }
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        match self {
+            Enum::A(x) => ::core::hash::Hasher::write_u64(x, i),
+            Enum::B(x) => ::core::hash::Hasher::write_u64(x, i),
+        }
+    }
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        match self {
+            Enum::A(x) => ::core::hash::Hasher::write_u128(x, i),
+            Enum::B(x) => ::core::hash::Hasher::write_u128(x, i),
+        }
+    }
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        match self {
+            Enum::A(x) => ::core::hash::Hasher::write_usize(x, i),
+            Enum::B(x) => ::core::hash::Hasher::write_usize(x, i),
+        }
+    }
+    #[inline]
+    fn write_i8(&mut self, i: i8) {
+        match self {
+            Enum::A(x) => ::core::hash::Hasher::write_i8(x, i),
+            Enum::B(x) => ::core::hash::Hasher::write_i8(x, i),
+        }
+    }
+    #[inline]
+    fn write_i16(&mut self, i: i16) {
+        match self {
+            Enum::A(x) => ::core::hash::Hasher::write_i16(x, i),
+            Enum::B(x) => ::core::hash::Hasher::write_i16(x, i),
+        }
+    }
+    #[inline]
+    fn write_i32(&mut self, i: i32) {
+        match self {
+            Enum::A(x) => ::core::hash::Hasher::write_i32(x, i),
+            Enum::B(x) => ::core::hash::Hasher::write_i32(x, i),
+        }
+    }
+    #[inline]
+    fn write_i64(&mut self, i: i64) {
+        match self {
+            Enum::A(x) => ::core::hash::Hasher::write_i64(x, i),
+            Enum::B(x) => ::core::hash::Hasher::write_i64(x, i),
+        }
+    }
+    #[inline]
+    fn write_i128(&mut self, i: i128) {
+        match self {
+            Enum::A(x) => ::core::hash::Hasher::write_i128(x, i),
+            Enum::B(x) => ::core::hash::Hasher::write_i128(x, i),
+        }
+    }
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        match self {
+            Enum::A(x) => ::core::hash::Hasher::write_isize(x, i),
+            Enum::B(x) => ::core::hash::Hasher::write_isize(x, i),
+        }
+    }
+}
+fn main() {}
@@ -0,0 +1,19 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+impl<A, B> ::core::hash::Hash for Enum<A, B>
+where
+    A: ::core::hash::Hash,
+    B: ::core::hash::Hash,
+{
+    #[inline]
+    fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+        match self {
+            Enum::A(x) => ::core::hash::Hash::hash(x, state),
+            Enum::B(x) => ::core::hash::Hash::hash(x, state),
+        }
+    }
+}
+fn main() {}
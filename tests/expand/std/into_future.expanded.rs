@@ -0,0 +1,53 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+impl<A, B> ::core::future::IntoFuture for Enum<A, B>
+where
+    A: ::core::future::IntoFuture,
+    B: ::core::future::IntoFuture<Output = <A as ::core::future::IntoFuture>::Output>,
+{
+    type Output = <A as ::core::future::IntoFuture>::Output;
+    type IntoFuture = __EnumIntoFuture<
+        <A as ::core::future::IntoFuture>::IntoFuture,
+        <B as ::core::future::IntoFuture>::IntoFuture,
+    >;
+    #[inline]
+    fn into_future(self) -> Self::IntoFuture {
+        match self {
+            Enum::A(x) => __EnumIntoFuture::A(::core::future::IntoFuture::into_future(x)),
+            Enum::B(x) => __EnumIntoFuture::B(::core::future::IntoFuture::into_future(x)),
+        }
+    }
+}
+#[doc(hidden)]
+enum __EnumIntoFuture<__T0, __T1> {
+    A(__T0),
+    B(__T1),
+}
+#[allow(unsafe_code)]
+impl<__T0, __T1> ::core::future::Future for __EnumIntoFuture<__T0, __T1>
+where
+    __T0: ::core::future::Future,
+    __T1: ::core::future::Future<Output = <__T0 as ::core::future::Future>::Output>,
+{
+    type Output = <__T0 as ::core::future::Future>::Output;
+    #[inline]
+    fn poll(
+        self: ::core::pin::Pin<&mut Self>,
+        cx: &mut ::core::task::Context<'_>,
+    ) -> ::core::task::Poll<Self::Output> {
+        unsafe {
+            match self.get_unchecked_mut() {
+                __EnumIntoFuture::A(x) => {
+                    ::core::future::Future::poll(::core::pin::Pin::new_unchecked(x), cx)
+                }
+                __EnumIntoFuture::B(x) => {
+                    ::core::future::Future::poll(::core::pin::Pin::new_unchecked(x), cx)
+                }
+            }
+        }
+    }
+}
+fn main() {}
@@ -80,5 +80,12 @@ where
             Enum::B(x) => ::std::io::BufRead::read_line(x, buf),
         }
     }
+    #[inline]
+    fn skip_until(&mut self, byte: u8) -> ::std::io::Result<usize> {
+        match self {
+            Enum::A(x) => ::std::io::BufRead::skip_until(x, byte),
+            Enum::B(x) => ::std::io::BufRead::skip_until(x, byte),
+        }
+    }
 }
 fn main() {}
@@ -15,5 +15,26 @@ where
             Enum::B(x) => ::std::io::Seek::seek(x, pos),
         }
     }
+    #[inline]
+    fn rewind(&mut self) -> ::std::io::Result<()> {
+        match self {
+            Enum::A(x) => ::std::io::Seek::rewind(x),
+            Enum::B(x) => ::std::io::Seek::rewind(x),
+        }
+    }
+    #[inline]
+    fn stream_position(&mut self) -> ::std::io::Result<u64> {
+        match self {
+            Enum::A(x) => ::std::io::Seek::stream_position(x),
+            Enum::B(x) => ::std::io::Seek::stream_position(x),
+        }
+    }
+    #[inline]
+    fn seek_relative(&mut self, offset: i64) -> ::std::io::Result<()> {
+        match self {
+            Enum::A(x) => ::std::io::Seek::seek_relative(x, offset),
+            Enum::B(x) => ::std::io::Seek::seek_relative(x, offset),
+        }
+    }
 }
 fn main() {}
@@ -0,0 +1,146 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+impl<A, B> ::core::iter::Iterator for Enum<A, B>
+where
+    A: ::core::iter::Iterator,
+    B: ::core::iter::Iterator<Item = <A as ::core::iter::Iterator>::Item>,
+{
+    type Item = <A as ::core::iter::Iterator>::Item;
+    #[inline]
+    fn next(&mut self) -> ::core::option::Option<Self::Item> {
+        match self {
+            Enum::A(x) => ::core::iter::Iterator::next(x),
+            Enum::B(x) => ::core::iter::Iterator::next(x),
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
+        match self {
+            Enum::A(x) => ::core::iter::Iterator::size_hint(x),
+            Enum::B(x) => ::core::iter::Iterator::size_hint(x),
+        }
+    }
+    #[inline]
+    fn count(self) -> usize {
+        match self {
+            Enum::A(x) => ::core::iter::Iterator::count(x),
+            Enum::B(x) => ::core::iter::Iterator::count(x),
+        }
+    }
+    #[inline]
+    fn last(self) -> ::core::option::Option<Self::Item> {
+        match self {
+            Enum::A(x) => ::core::iter::Iterator::last(x),
+            Enum::B(x) => ::core::iter::Iterator::last(x),
+        }
+    }
+    #[inline]
+    fn nth(&mut self, n: usize) -> ::core::option::Option<Self::Item> {
+        match self {
+            Enum::A(x) => ::core::iter::Iterator::nth(x, n),
+            Enum::B(x) => ::core::iter::Iterator::nth(x, n),
+        }
+    }
+    #[inline]
+    fn advance_by(
+        &mut self,
+        n: usize,
+    ) -> ::core::result::Result<(), ::core::num::NonZero<usize>> {
+        match self {
+            Enum::A(x) => ::core::iter::Iterator::advance_by(x, n),
+            Enum::B(x) => ::core::iter::Iterator::advance_by(x, n),
+        }
+    }
+    #[inline]
+    #[must_use = "if you really need to exhaust the iterator, consider `.for_each(drop)` instead"]
+    fn collect<__U: ::core::iter::FromIterator<Self::Item>>(self) -> __U {
+        match self {
+            Enum::A(x) => ::core::iter::Iterator::collect(x),
+            Enum::B(x) => ::core::iter::Iterator::collect(x),
+        }
+    }
+    #[inline]
+    fn fold<__U, __F>(self, init: __U, f: __F) -> __U
+    where
+        __F: ::core::ops::FnMut(__U, Self::Item) -> __U,
+    {
+        match self {
+            Enum::A(x) => ::core::iter::Iterator::fold(x, init, f),
+            Enum::B(x) => ::core::iter::Iterator::fold(x, init, f),
+        }
+    }
+    #[inline]
+    fn find<__P>(&mut self, predicate: __P) -> ::core::option::Option<Self::Item>
+    where
+        __P: ::core::ops::FnMut(&Self::Item) -> bool,
+    {
+        match self {
+            Enum::A(x) => ::core::iter::Iterator::find(x, predicate),
+            Enum::B(x) => ::core::iter::Iterator::find(x, predicate),
+        }
+    }
+    #[inline]
+    fn find_map<__U, __F>(&mut self, f: __F) -> ::core::option::Option<__U>
+    where
+        __F: ::core::ops::FnMut(Self::Item) -> ::core::option::Option<__U>,
+    {
+        match self {
+            Enum::A(x) => ::core::iter::Iterator::find_map(x, f),
+            Enum::B(x) => ::core::iter::Iterator::find_map(x, f),
+        }
+    }
+}
+impl<A, B> ::core::iter::DoubleEndedIterator for Enum<A, B>
+where
+    A: ::core::iter::DoubleEndedIterator,
+    B: ::core::iter::DoubleEndedIterator<Item = <A as ::core::iter::Iterator>::Item>,
+{
+    #[inline]
+    fn next_back(&mut self) -> ::core::option::Option<Self::Item> {
+        match self {
+            Enum::A(x) => ::core::iter::DoubleEndedIterator::next_back(x),
+            Enum::B(x) => ::core::iter::DoubleEndedIterator::next_back(x),
+        }
+    }
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> ::core::option::Option<Self::Item> {
+        match self {
+            Enum::A(x) => ::core::iter::DoubleEndedIterator::nth_back(x, n),
+            Enum::B(x) => ::core::iter::DoubleEndedIterator::nth_back(x, n),
+        }
+    }
+    #[inline]
+    fn advance_back_by(
+        &mut self,
+        n: usize,
+    ) -> ::core::result::Result<(), ::core::num::NonZero<usize>> {
+        match self {
+            Enum::A(x) => ::core::iter::DoubleEndedIterator::advance_back_by(x, n),
+            Enum::B(x) => ::core::iter::DoubleEndedIterator::advance_back_by(x, n),
+        }
+    }
+    #[inline]
+    fn rfold<__U, __F>(self, accum: __U, f: __F) -> __U
+    where
+        __F: ::core::ops::FnMut(__U, Self::Item) -> __U,
+    {
+        match self {
+            Enum::A(x) => ::core::iter::DoubleEndedIterator::rfold(x, accum, f),
+            Enum::B(x) => ::core::iter::DoubleEndedIterator::rfold(x, accum, f),
+        }
+    }
+    #[inline]
+    fn rfind<__P>(&mut self, predicate: __P) -> ::core::option::Option<Self::Item>
+    where
+        __P: ::core::ops::FnMut(&Self::Item) -> bool,
+    {
+        match self {
+            Enum::A(x) => ::core::iter::DoubleEndedIterator::rfind(x, predicate),
+            Enum::B(x) => ::core::iter::DoubleEndedIterator::rfind(x, predicate),
+        }
+    }
+}
+fn main() {}
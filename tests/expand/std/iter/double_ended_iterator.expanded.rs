@@ -38,6 +38,13 @@ where
         }
     }
     #[inline]
+    fn nth(&mut self, n: usize) -> ::core::option::Option<Self::Item> {
+        match self {
+            Enum::A(x) => ::core::iter::Iterator::nth(x, n),
+            Enum::B(x) => ::core::iter::Iterator::nth(x, n),
+        }
+    }
+    #[inline]
     #[must_use = "if you really need to exhaust the iterator, consider `.for_each(drop)` instead"]
     fn collect<__U: ::core::iter::FromIterator<Self::Item>>(self) -> __U {
         match self {
@@ -89,6 +96,13 @@ where
         }
     }
     #[inline]
+    fn nth_back(&mut self, n: usize) -> ::core::option::Option<Self::Item> {
+        match self {
+            Enum::A(x) => ::core::iter::DoubleEndedIterator::nth_back(x, n),
+            Enum::B(x) => ::core::iter::DoubleEndedIterator::nth_back(x, n),
+        }
+    }
+    #[inline]
     fn rfold<__U, __F>(self, accum: __U, f: __F) -> __U
     where
         __F: ::core::ops::FnMut(__U, Self::Item) -> __U,
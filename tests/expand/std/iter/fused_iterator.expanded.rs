@@ -38,6 +38,13 @@ where
         }
     }
     #[inline]
+    fn nth(&mut self, n: usize) -> ::core::option::Option<Self::Item> {
+        match self {
+            Enum::A(x) => ::core::iter::Iterator::nth(x, n),
+            Enum::B(x) => ::core::iter::Iterator::nth(x, n),
+        }
+    }
+    #[inline]
     #[must_use = "if you really need to exhaust the iterator, consider `.for_each(drop)` instead"]
     fn collect<__U: ::core::iter::FromIterator<Self::Item>>(self) -> __U {
         match self {
@@ -80,6 +87,5 @@ impl<A, B> ::core::iter::FusedIterator for Enum<A, B>
 where
     A: ::core::iter::FusedIterator,
     B: ::core::iter::FusedIterator<Item = <A as ::core::iter::Iterator>::Item>,
-{
-}
+{}
 fn main() {}
@@ -0,0 +1,9 @@
+use auto_enums::enum_derive;
+
+#[enum_derive(ExactSizeIterator, iter::IsEmpty)]
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+
+fn main() {}
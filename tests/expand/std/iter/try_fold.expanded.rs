@@ -0,0 +1,118 @@
+use auto_enums::enum_derive;
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+impl<A, B> ::core::iter::Iterator for Enum<A, B>
+where
+    A: ::core::iter::Iterator,
+    B: ::core::iter::Iterator<Item = <A as ::core::iter::Iterator>::Item>,
+{
+    type Item = <A as ::core::iter::Iterator>::Item;
+    #[inline]
+    fn next(&mut self) -> ::core::option::Option<Self::Item> {
+        match self {
+            Enum::A(x) => ::core::iter::Iterator::next(x),
+            Enum::B(x) => ::core::iter::Iterator::next(x),
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
+        match self {
+            Enum::A(x) => ::core::iter::Iterator::size_hint(x),
+            Enum::B(x) => ::core::iter::Iterator::size_hint(x),
+        }
+    }
+    #[inline]
+    fn count(self) -> usize {
+        match self {
+            Enum::A(x) => ::core::iter::Iterator::count(x),
+            Enum::B(x) => ::core::iter::Iterator::count(x),
+        }
+    }
+    #[inline]
+    fn last(self) -> ::core::option::Option<Self::Item> {
+        match self {
+            Enum::A(x) => ::core::iter::Iterator::last(x),
+            Enum::B(x) => ::core::iter::Iterator::last(x),
+        }
+    }
+    #[inline]
+    fn nth(&mut self, n: usize) -> ::core::option::Option<Self::Item> {
+        match self {
+            Enum::A(x) => ::core::iter::Iterator::nth(x, n),
+            Enum::B(x) => ::core::iter::Iterator::nth(x, n),
+        }
+    }
+    #[inline]
+    #[must_use = "if you really need to exhaust the iterator, consider `.for_each(drop)` instead"]
+    fn collect<__U: ::core::iter::FromIterator<Self::Item>>(self) -> __U {
+        match self {
+            Enum::A(x) => ::core::iter::Iterator::collect(x),
+            Enum::B(x) => ::core::iter::Iterator::collect(x),
+        }
+    }
+    #[inline]
+    fn fold<__U, __F>(self, init: __U, f: __F) -> __U
+    where
+        __F: ::core::ops::FnMut(__U, Self::Item) -> __U,
+    {
+        match self {
+            Enum::A(x) => ::core::iter::Iterator::fold(x, init, f),
+            Enum::B(x) => ::core::iter::Iterator::fold(x, init, f),
+        }
+    }
+    #[inline]
+    fn try_fold<__U, __F, __R>(&mut self, init: __U, f: __F) -> __R
+    where
+        __F: ::core::ops::FnMut(__U, Self::Item) -> __R,
+        __R: ::core::ops::Try<Output = __U>,
+    {
+        match self {
+            Enum::A(x) => ::core::iter::Iterator::try_fold(x, init, f),
+            Enum::B(x) => ::core::iter::Iterator::try_fold(x, init, f),
+        }
+    }
+}
+impl<A, B> ::core::iter::DoubleEndedIterator for Enum<A, B>
+where
+    A: ::core::iter::DoubleEndedIterator,
+    B: ::core::iter::DoubleEndedIterator<Item = <A as ::core::iter::Iterator>::Item>,
+{
+    #[inline]
+    fn next_back(&mut self) -> ::core::option::Option<Self::Item> {
+        match self {
+            Enum::A(x) => ::core::iter::DoubleEndedIterator::next_back(x),
+            Enum::B(x) => ::core::iter::DoubleEndedIterator::next_back(x),
+        }
+    }
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> ::core::option::Option<Self::Item> {
+        match self {
+            Enum::A(x) => ::core::iter::DoubleEndedIterator::nth_back(x, n),
+            Enum::B(x) => ::core::iter::DoubleEndedIterator::nth_back(x, n),
+        }
+    }
+    #[inline]
+    fn rfold<__U, __F>(self, accum: __U, f: __F) -> __U
+    where
+        __F: ::core::ops::FnMut(__U, Self::Item) -> __U,
+    {
+        match self {
+            Enum::A(x) => ::core::iter::DoubleEndedIterator::rfold(x, accum, f),
+            Enum::B(x) => ::core::iter::DoubleEndedIterator::rfold(x, accum, f),
+        }
+    }
+    #[inline]
+    fn try_rfold<__U, __F, __R>(&mut self, init: __U, f: __F) -> __R
+    where
+        __F: ::core::ops::FnMut(__U, Self::Item) -> __R,
+        __R: ::core::ops::Try<Output = __U>,
+    {
+        match self {
+            Enum::A(x) => ::core::iter::DoubleEndedIterator::try_rfold(x, init, f),
+            Enum::B(x) => ::core::iter::DoubleEndedIterator::try_rfold(x, init, f),
+        }
+    }
+}
+fn main() {}
@@ -0,0 +1,9 @@
+use auto_enums::enum_derive;
+
+#[enum_derive(DoubleEndedIterator, iter::TryFold)]
+enum Enum<A, B> {
+    A(A),
+    B(B),
+}
+
+fn main() {}
@@ -6,6 +6,8 @@ use std::{
 };
 
 const PATH: &str = "tests/expand/**/*.rs";
+// The code generated when the optional features are disabled.
+const DEFAULT_FEATURES_PATH: &str = "tests/expand-default-features/**/*.rs";
 
 #[rustversion::attr(not(nightly), ignore)]
 #[test]
@@ -20,11 +22,14 @@ fn expandtest() {
     }
 
     let args = &["--all-features"];
+    let default_features_args: &[&str] = &[];
     if is_ci {
         macrotest::expand_without_refresh_args(PATH, args);
+        macrotest::expand_without_refresh_args(DEFAULT_FEATURES_PATH, default_features_args);
     } else {
         env::set_var("MACROTEST", "overwrite");
         macrotest::expand_args(PATH, args);
+        macrotest::expand_args(DEFAULT_FEATURES_PATH, default_features_args);
     }
 }
 
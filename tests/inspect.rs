@@ -0,0 +1,69 @@
+#![warn(rust_2018_idioms, single_use_lifetimes)]
+#![allow(dead_code)]
+
+use auto_enums::auto_enum;
+
+#[auto_enum]
+fn match_(x: usize) -> (usize, usize, &'static str) {
+    #[auto_enum(Iterator, inspect)]
+    let iter = match x {
+        0 => 1..8,
+        n if n > 3 => 2..=10,
+        _ => (0..2).map(|x| x + 1),
+    };
+    (iter.variant_count(), iter.variant_index(), iter.variant_source())
+}
+
+#[auto_enum]
+fn nested(x: usize) -> (usize, usize, &'static str) {
+    #[auto_enum(Iterator, inspect)]
+    let iter = match x {
+        0 => 1..8,
+        _ =>
+        {
+            #[nested]
+            match x {
+                1 => vec![5, 10].into_iter(),
+                _ => {
+                    let v = vec![1, 2];
+                    marker!(v.into_iter().rev())
+                }
+            }
+        }
+    };
+    (iter.variant_count(), iter.variant_index(), iter.variant_source())
+}
+
+#[test]
+fn inspect() {
+    assert_eq!(match_(0).0, 3);
+    assert_eq!(match_(0).1, 0);
+    assert_eq!(match_(4).1, 1);
+    assert_eq!(match_(1).1, 2);
+
+    assert_eq!(nested(0).0, 3);
+    assert_eq!(nested(1).1, 1);
+    assert_eq!(nested(2).1, 2);
+}
+
+// Spans can only be joined on nightly, so the source text of the branches is
+// only available there.
+#[rustversion::nightly]
+#[test]
+fn inspect_source_text() {
+    assert_eq!(match_(0).2, "1..8");
+    assert_eq!(match_(4).2, "2..=10");
+    assert_eq!(match_(1).2, "(0..2).map(|x| x + 1)");
+    assert_eq!(nested(1).2, "vec![5, 10].into_iter()");
+    assert_eq!(nested(2).2, "v.into_iter().rev()");
+}
+
+#[rustversion::not(nightly)]
+#[test]
+fn inspect_tokens() {
+    assert_eq!(match_(0).2, "1 .. 8");
+    assert_eq!(match_(4).2, "2 ..= 10");
+    assert_eq!(match_(1).2, "(0 .. 2).map(| x | x + 1)");
+    assert_eq!(nested(1).2, "vec! [5, 10].into_iter()");
+    assert_eq!(nested(2).2, "v.into_iter().rev()");
+}
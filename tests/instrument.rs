@@ -0,0 +1,64 @@
+#![cfg(all(feature = "std", feature = "tracing", feature = "tracing_subscriber"))]
+#![warn(rust_2018_idioms, single_use_lifetimes)]
+
+extern crate tracing_crate as tracing;
+extern crate tracing_subscriber_crate as tracing_subscriber;
+
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use auto_enums::auto_enum;
+use tracing::{
+    field::{Field, Visit},
+    Event, Subscriber,
+};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+type Events = Arc<Mutex<Vec<(u64, Option<u32>)>>>;
+
+struct Branches(Events);
+
+impl<S: Subscriber> Layer<S> for Branches {
+    fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+        struct Branch(Option<u64>);
+        impl Visit for Branch {
+            fn record_u64(&mut self, field: &Field, value: u64) {
+                if field.name() == "branch" {
+                    self.0 = Some(value);
+                }
+            }
+            fn record_debug(&mut self, _: &Field, _: &dyn fmt::Debug) {}
+        }
+
+        let mut branch = Branch(None);
+        event.record(&mut branch);
+        self.0.lock().unwrap().extend(branch.0.map(|b| (b, event.metadata().line())));
+    }
+}
+
+// The line of the event is that of the branch.
+const LINE: u32 = line!();
+#[auto_enum(Iterator, instrument)]
+fn match_(x: usize) -> impl Iterator<Item = i32> {
+    match x {
+        0 => 1..8,
+        n if n > 3 => 2..=10,
+        _ => (0..2).map(|x| x + 1),
+    }
+}
+
+#[test]
+fn instrument() {
+    const ANS: &[i32] = &[28, 3];
+
+    let branches = Arc::new(Mutex::new(Vec::new()));
+    let subscriber = tracing_subscriber::registry().with(Branches(branches.clone()));
+    tracing::subscriber::with_default(subscriber, || {
+        for (i, x) in ANS.iter().enumerate() {
+            assert_eq!(match_(i).sum::<i32>(), *x);
+        }
+    });
+    assert_eq!(*branches.lock().unwrap(), [(0, Some(LINE + 4)), (2, Some(LINE + 6))]);
+}
@@ -66,6 +66,30 @@ mod marker {
             _ => 0..2,
         }
     }
+
+    #[auto_enum(inspect, Iterator, inspect)] //~ ERROR duplicate `inspect` argument
+    fn multiple_inspect(x: usize) -> impl Iterator<Item = i32> {
+        match x {
+            0 => 1..=8,
+            _ => 0..2,
+        }
+    }
+
+    #[auto_enum(instrument, instrument, Iterator)] //~ ERROR duplicate `instrument` argument
+    fn multiple_instrument(x: usize) -> impl Iterator<Item = i32> {
+        match x {
+            0 => 1..=8,
+            _ => 0..2,
+        }
+    }
+
+    #[auto_enum(Iterator, crate(std = ::my_std))] //~ ERROR the path of the `std` crate cannot be changed
+    fn crate_std(x: usize) -> impl Iterator<Item = i32> {
+        match x {
+            0 => 1..=8,
+            _ => 0..2,
+        }
+    }
 }
 
 fn main() {}
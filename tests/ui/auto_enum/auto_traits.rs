@@ -0,0 +1,29 @@
+use std::{cell::Cell, rc::Rc};
+
+use auto_enums::auto_enum;
+
+#[auto_enum(Iterator, Send)]
+fn send(x: usize) -> impl Iterator<Item = i32> {
+    match x {
+        0 => 1..8,
+        _ => vec![Rc::new(1)].into_iter().map(|x| *x), //~ ERROR `Rc<{integer}>` cannot be sent between threads safely
+    }
+}
+
+#[auto_enum(Iterator, Sync)]
+fn sync(x: usize) -> impl Iterator<Item = i32> {
+    match x {
+        0 => 1..8,
+        _ => vec![Cell::new(1)].into_iter().map(|x| x.get()), //~ ERROR `Cell<{integer}>` cannot be shared between threads safely
+    }
+}
+
+#[auto_enum(Iterator, UnwindSafe)]
+fn unwind_safe(x: usize) -> impl Iterator<Item = i32> {
+    match x {
+        0 => 1..8,
+        _ => vec![Rc::new(Cell::new(1))].into_iter().map(|x| x.get()), //~ ERROR may contain interior mutability
+    }
+}
+
+fn main() {}
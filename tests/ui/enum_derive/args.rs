@@ -24,4 +24,28 @@ enum Enum4<A, B> {
     B(B),
 }
 
+#[enum_derive(Iterator, crate(futures = ::futures), crate(tokio = ::tokio))] //~ ERROR duplicate `crate` argument
+enum Enum5<A, B> {
+    A(A),
+    B(B),
+}
+
+#[enum_derive(Iterator, crate(futures = ::futures, futures = ::futures))] //~ ERROR duplicate crate name `futures`
+enum Enum6<A, B> {
+    A(A),
+    B(B),
+}
+
+#[enum_derive(Iterator, crate(futures))] //~ ERROR expected `=`
+enum Enum7<A, B> {
+    A(A),
+    B(B),
+}
+
+#[enum_derive(Iterator, crate(core = ::my_core))] //~ ERROR the path of the `core` crate cannot be changed
+enum Enum8<A, B> {
+    A(A),
+    B(B),
+}
+
 fn main() {}
@@ -0,0 +1,23 @@
+use auto_enums::enum_derive;
+
+// `project` requires that the enum is `Unpin` only if all fields are `Unpin`.
+#[enum_derive(Project)]
+enum ManualUnpin<A, B> {
+    A(A),
+    B(B),
+}
+
+impl<A, B> Unpin for ManualUnpin<A, B> {} //~ ERROR conflicting implementations of trait `Unpin`
+
+// `project` requires that the enum does not implement `Drop`.
+#[enum_derive(Project)] //~ ERROR conflicting implementations of trait `MustNotImplDrop`
+enum ManualDrop<A, B> {
+    A(A),
+    B(B),
+}
+
+impl<A, B> Drop for ManualDrop<A, B> {
+    fn drop(&mut self) {}
+}
+
+fn main() {}
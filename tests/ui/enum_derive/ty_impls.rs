@@ -9,4 +9,11 @@ enum Enum1<A, B> {
     B(B),
 }
 
+#[enum_derive(Inner)]
+enum Enum2<A, B> {
+    //~^ ERROR `enum_derive(Inner)` all fields need to be generics
+    A(Foo<A>),
+    B(B),
+}
+
 fn main() {}
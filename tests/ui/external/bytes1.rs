@@ -0,0 +1,11 @@
+extern crate bytes1_crate as bytes;
+
+use auto_enums::enum_derive;
+
+#[enum_derive(bytes1::Buf, bytes1::BufMut)]
+enum Bytes<A, B> {
+    A(A),
+    B(B),
+}
+
+fn main() {}
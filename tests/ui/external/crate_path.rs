@@ -0,0 +1,62 @@
+// Check that `crate(...)` argument changes the root path of the generated impls.
+
+use auto_enums::{auto_enum, enum_derive};
+
+mod facade {
+    pub use futures03_crate as futures;
+    pub use tokio1_crate as tokio;
+    pub use tracing_crate as tracing;
+    pub use tracing_core_crate as tracing_core;
+}
+
+#[enum_derive(
+    futures03::Stream,
+    futures03::AsyncRead,
+    tokio1::AsyncRead,
+    crate(futures = crate::facade::futures, tokio = crate::facade::tokio)
+)]
+enum Enum1<A, B> {
+    A(A),
+    B(B),
+}
+
+#[auto_enum(futures03::Stream, crate(futures = crate::facade::futures))]
+fn stream(x: bool) -> impl facade::futures::Stream<Item = i32> {
+    if x {
+        facade::futures::stream::iter(1..=8)
+    } else {
+        facade::futures::stream::empty()
+    }
+}
+
+#[enum_derive(tracing_core::Subscriber, crate(tracing_core = crate::facade::tracing_core))]
+enum Enum2<A, B> {
+    A(A),
+    B(B),
+}
+
+// `tracing::Subscriber` also refers to `tracing_core`.
+#[enum_derive(tracing::Subscriber, crate(tracing_core = crate::facade::tracing_core))]
+enum Enum4<A, B> {
+    A(A),
+    B(B),
+}
+
+// `tracing` is only used by `instrument`, not by the generated enum.
+#[auto_enum(Iterator, instrument, crate(tracing = crate::facade::tracing))]
+fn iter(x: bool) -> impl Iterator<Item = i32> {
+    if x {
+        1..8
+    } else {
+        vec![5, 10].into_iter()
+    }
+}
+
+// Crates that are not used by the generated code are ignored.
+#[enum_derive(Iterator, crate(futures = ::my_futures, serde = ::my_serde))]
+enum Enum3<A, B> {
+    A(A),
+    B(B),
+}
+
+fn main() {}
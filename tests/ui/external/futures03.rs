@@ -4,6 +4,7 @@ use auto_enums::enum_derive;
 
 #[enum_derive(
     futures03::Stream,
+    futures03::FusedStream,
     futures03::Sink,
     futures03::AsyncRead,
     futures03::AsyncWrite,
@@ -15,4 +16,10 @@ enum Futures03<A, B> {
     B(B),
 }
 
+#[enum_derive(Future, futures03::FusedFuture)]
+enum FusedFuture<A, B> {
+    A(A),
+    B(B),
+}
+
 fn main() {}
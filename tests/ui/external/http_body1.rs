@@ -0,0 +1,11 @@
+extern crate http_body1_crate as http_body;
+
+use auto_enums::enum_derive;
+
+#[enum_derive(http_body1::Body)]
+enum Body<A, B> {
+    A(A),
+    B(B),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+extern crate log_crate as log;
+
+use auto_enums::enum_derive;
+
+#[enum_derive(log::Log)]
+enum Log<A, B> {
+    A(A),
+    B(B),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+extern crate rand_core06_crate as rand_core;
+
+use auto_enums::enum_derive;
+
+#[enum_derive(rand_core06::RngCore, rand_core06::CryptoRng)]
+enum Rng<A, B> {
+    A(A),
+    B(B),
+}
+
+fn main() {}
@@ -2,10 +2,16 @@ extern crate serde_crate as serde;
 
 use auto_enums::enum_derive;
 
-#[enum_derive(serde::Serialize)]
+#[enum_derive(serde::Serialize, serde::Deserialize)]
 enum Serde<A, B> {
     A(A),
     B(B),
 }
 
+#[enum_derive(serde::Serializer)]
+enum Serializer<A, B> {
+    A(A),
+    B(B),
+}
+
 fn main() {}
@@ -0,0 +1,11 @@
+extern crate tower_service_crate as tower;
+
+use auto_enums::enum_derive;
+
+#[enum_derive(tower::Service)]
+enum Service<A, B> {
+    A(A),
+    B(B),
+}
+
+fn main() {}
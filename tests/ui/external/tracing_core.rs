@@ -0,0 +1,11 @@
+extern crate tracing_core_crate as tracing_core;
+
+use auto_enums::enum_derive;
+
+#[enum_derive(tracing_core::Subscriber)]
+enum Subscriber<A, B> {
+    A(A),
+    B(B),
+}
+
+fn main() {}
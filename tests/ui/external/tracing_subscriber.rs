@@ -0,0 +1,12 @@
+extern crate tracing_core_crate as tracing_core;
+extern crate tracing_subscriber_crate as tracing_subscriber;
+
+use auto_enums::enum_derive;
+
+#[enum_derive(tracing_subscriber::Layer)]
+enum Layer<A, B> {
+    A(A),
+    B(B),
+}
+
+fn main() {}